@@ -1,5 +1,7 @@
 mod map;
 mod set;
+mod sorted_vec_map;
 
 pub use map::*;
 pub use set::*;
+pub use sorted_vec_map::*;
@@ -0,0 +1,119 @@
+use crate::v1::collection::Map;
+
+/// A map stored as a vector of entries sorted by keys.
+///
+/// Lookups are binary searches, so the map takes exactly as much memory as its entries
+/// and is best suited for tables which are built once and then only queried.
+/// Building it with [`FromIterator`] sorts the entries once, later values win for equal keys.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::collection::{Map, SortedVecMap};
+///
+/// let mut map: SortedVecMap<u32, char> = [(3, 'a'), (1, 'b'), (3, 'c')].into_iter().collect();
+///
+/// assert_eq!(2, map.len());
+/// assert_eq!(Some('c'), map.get(&3));
+/// assert_eq!(None, map.insert(2, 'd'));
+/// assert_eq!(Some('b'), map.erase(&1));
+/// assert_eq!(None, map.get(&1));
+/// ```
+/// # Implementation details
+/// - Lookups and updates of existing keys take O(log<sub>2</sub>(N)) time.
+/// - Insertions of new keys and erasures shift the tail of the vector, so they take O(N) time.
+/// - Building from an iterator takes O(N * log<sub>2</sub>(N)) time.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SortedVecMap<K, V> {
+    entries: Vec<(K, V)>,
+}
+
+impl<K: Ord, V> SortedVecMap<K, V> {
+    /// Creates an empty map.
+    /// # Examples
+    /// ```
+    /// use ads_rs::prelude::v1::collection::SortedVecMap;
+    ///
+    /// let map: SortedVecMap<u32, char> = SortedVecMap::new();
+    ///
+    /// assert!(map.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self { entries: vec![] }
+    }
+
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Checks whether the map has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn position(&self, key: &K) -> Result<usize, usize> {
+        self.entries.binary_search_by(|(k, _)| k.cmp(key))
+    }
+}
+
+impl<K: Ord, V: Clone> Map<K, V> for SortedVecMap<K, V> {
+    fn get(&self, key: &K) -> Option<V> {
+        self.position(key).ok().map(|i| self.entries[i].1.clone())
+    }
+
+    fn erase(&mut self, key: &K) -> Option<V> {
+        self.position(key).ok().map(|i| self.entries.remove(i).1)
+    }
+
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.position(&key) {
+            Ok(i) => Some(std::mem::replace(&mut self.entries[i].1, value)),
+            Err(i) => {
+                self.entries.insert(i, (key, value));
+                None
+            }
+        }
+    }
+}
+
+impl<K: Ord, V> FromIterator<(K, V)> for SortedVecMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut entries: Vec<(K, V)> = iter.into_iter().collect();
+
+        // the sort is stable, so the last value of equal keys is moved into the kept entry
+        entries.sort_by(|lhs, rhs| lhs.0.cmp(&rhs.0));
+        entries.dedup_by(|next, prev| {
+            if next.0 == prev.0 {
+                std::mem::swap(next, prev);
+                true
+            } else {
+                false
+            }
+        });
+
+        Self { entries }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sorted_vec_map_works() {
+        // arrange
+        let mut map: SortedVecMap<u32, char> = [(3, 'a'), (1, 'b'), (3, 'c'), (2, 'd')]
+            .into_iter()
+            .collect();
+
+        // act
+        let built = (map.len(), map.get(&1), map.get(&3), map.get(&4));
+        let inserted = (map.insert(4, 'e'), map.insert(1, 'f'));
+        let erased = (map.erase(&2), map.erase(&2));
+
+        // assert
+        assert_eq!((3, Some('b'), Some('c'), None), built);
+        assert_eq!((None, Some('b')), inserted);
+        assert_eq!((Some('d'), None), erased);
+        assert_eq!(vec![(1, 'f'), (3, 'c'), (4, 'e')], map.entries);
+    }
+}
@@ -0,0 +1,488 @@
+use crate::v1::math::big_uint::{forward_binop, forward_primitive_binop};
+use crate::v1::math::{BigUint, ParseBigIntError};
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter};
+use std::iter::{Product, Sum};
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Shl, ShlAssign, Shr,
+    ShrAssign, Sub, SubAssign,
+};
+use std::str::FromStr;
+
+/// An arbitrary precision signed integer.
+///
+/// The number is stored as a sign and a [`BigUint`] magnitude, zero is never negative,
+/// so the derived `Eq` and `Hash` are consistent with the value.
+/// Division and remainder truncate towards zero like the primitive integers do,
+/// use [`BigInt::rem_euclid`] for a non-negative remainder.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::{BigInt, BigUint};
+///
+/// let a: BigInt = "-170141183460469231731687303715884105729".parse().unwrap();
+/// let b = BigInt::from(10);
+///
+/// assert_eq!("-170141183460469231731687303715884105719", (&a + &b).to_string());
+/// assert_eq!(BigInt::from(i128::MIN), &a + 1);
+/// assert_eq!((BigInt::from(i128::MIN / 10), BigInt::from(-9)), a.div_rem(&b));
+/// assert_eq!(BigInt::from(1), a.rem_euclid(&b));
+/// assert_eq!(BigInt::from(-1) << 127, (&a + 1) >> 0);
+/// assert_eq!(BigInt::from(-2), BigInt::from(-3) >> 1);
+/// assert_eq!(BigUint::from(5u64), BigInt::from(-5).unsigned_abs());
+/// ```
+/// ## Corner cases
+/// - `-0` is parsed and normalized into `0`.
+/// - Right shift rounds towards negative infinity, like arithmetic shift of the primitive integers.
+/// ```
+/// use ads_rs::prelude::v1::math::BigInt;
+///
+/// assert_eq!(BigInt::ZERO, "-0".parse().unwrap());
+/// assert_eq!(BigInt::from(-1), BigInt::from(-1) >> 100);
+/// assert_eq!("0", (-BigInt::ZERO).to_string());
+/// ```
+/// # Panics
+/// Division by zero panics.
+/// # Implementation details
+/// - Every operation is reduced to [`BigUint`] operations on the magnitudes.
+/// - Time complexity of every operation is the same as of the [`BigUint`] one.
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigInt {
+    negative: bool,
+    magnitude: BigUint,
+}
+
+impl BigInt {
+    /// The number 0.
+    pub const ZERO: Self = Self {
+        negative: false,
+        magnitude: BigUint::ZERO,
+    };
+
+    /// Creates a number from a sign and a magnitude, the sign of zero is ignored.
+    pub fn from_sign_magnitude(negative: bool, magnitude: BigUint) -> Self {
+        Self {
+            negative: negative && !magnitude.is_zero(),
+            magnitude,
+        }
+    }
+
+    /// Checks whether the number equals zero.
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_zero()
+    }
+
+    /// Checks whether the number is less than zero.
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns -1, 0 or 1 depending on the sign of the number.
+    pub fn signum(&self) -> i32 {
+        match (self.negative, self.is_zero()) {
+            (true, _) => -1,
+            (false, true) => 0,
+            (false, false) => 1,
+        }
+    }
+
+    /// Returns the absolute value.
+    pub fn magnitude(&self) -> &BigUint {
+        &self.magnitude
+    }
+
+    /// Converts the number into its absolute value.
+    pub fn unsigned_abs(self) -> BigUint {
+        self.magnitude
+    }
+
+    /// Converts the number into `i64`, `None` if it doesn't fit.
+    pub fn to_i64(&self) -> Option<i64> {
+        let value = self.to_i128()?;
+
+        i64::try_from(value).ok()
+    }
+
+    /// Converts the number into `i128`, `None` if it doesn't fit.
+    pub fn to_i128(&self) -> Option<i128> {
+        let magnitude = self.magnitude.to_u128()?;
+
+        if self.negative {
+            0i128.checked_sub_unsigned(magnitude)
+        } else {
+            i128::try_from(magnitude).ok()
+        }
+    }
+
+    /// Finds the quotient truncated towards zero and the remainder of the division by `rhs`.
+    /// The remainder has the sign of `self`.
+    /// # Panics
+    /// Panics if `rhs` equals zero.
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        let (quot, rem) = self.magnitude.div_rem(&rhs.magnitude);
+
+        (
+            Self::from_sign_magnitude(self.negative != rhs.negative, quot),
+            Self::from_sign_magnitude(self.negative, rem),
+        )
+    }
+
+    /// Finds the least non-negative remainder of the division by `rhs`.
+    /// # Panics
+    /// Panics if `rhs` equals zero.
+    pub fn rem_euclid(&self, rhs: &Self) -> Self {
+        let rem = &self.magnitude % &rhs.magnitude;
+
+        if self.negative && !rem.is_zero() {
+            Self::from(&rhs.magnitude - rem)
+        } else {
+            Self::from(rem)
+        }
+    }
+
+    /// Raises the number to the power of `exp`, 0<sup>0</sup> equals 1.
+    pub fn pow(&self, exp: u32) -> Self {
+        Self::from_sign_magnitude(self.negative && exp % 2 == 1, self.magnitude.pow(exp))
+    }
+
+    /// Finds a GCD (Greatest Common Divisor) of the absolute values, GCD of two zeros equals 0.
+    pub fn gcd(&self, rhs: &Self) -> BigUint {
+        self.magnitude.gcd(&rhs.magnitude)
+    }
+
+    /// Finds an extended GCD (Greatest Common Divisor): a tuple `(gcd, x, y)` such that
+    ///
+    /// x * self + y * rhs = gcd(self, rhs)
+    ///
+    /// Like in [`extended_gcd_signed`](crate::v1::math::extended_gcd_signed), [`BigUint::extended_gcd`]
+    /// is applied to the absolute values, then the coefficients' signs are fixed.
+    pub fn extended_gcd(&self, rhs: &Self) -> (BigUint, Self, Self) {
+        let (gcd, x, y) = self.magnitude.extended_gcd(&rhs.magnitude);
+
+        (
+            gcd,
+            if self.negative { -x } else { x },
+            if rhs.negative { -y } else { y },
+        )
+    }
+
+    /// Parses a number in the given radix, an optional `+` or `-` sign is allowed.
+    /// # Panics
+    /// Panics if `radix` isn't in the range `[2, 36]`.
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseBigIntError> {
+        match s.strip_prefix('-') {
+            Some(digits) if !digits.starts_with('+') => Ok(Self::from_sign_magnitude(
+                true,
+                BigUint::from_str_radix(digits, radix)?,
+            )),
+            Some(_) => Err(ParseBigIntError::InvalidDigit),
+            None => Ok(Self::from(BigUint::from_str_radix(s, radix)?)),
+        }
+    }
+
+    /// Formats the number in the given radix with lowercase digits.
+    /// # Panics
+    /// Panics if `radix` isn't in the range `[2, 36]`.
+    pub fn to_str_radix(&self, radix: u32) -> String {
+        let digits = self.magnitude.to_str_radix(radix);
+
+        if self.negative {
+            format!("-{digits}")
+        } else {
+            digits
+        }
+    }
+}
+
+macro_rules! impl_from_primitive {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for BigInt {
+                fn from(value: $t) -> Self {
+                    Self::from_sign_magnitude(value < 0, BigUint::from(value.unsigned_abs()))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_primitive!(i8, i16, i32, i64, i128, isize);
+
+impl From<BigUint> for BigInt {
+    fn from(value: BigUint) -> Self {
+        Self::from_sign_magnitude(false, value)
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> Self::Output {
+        Self::from_sign_magnitude(!self.negative, self.magnitude)
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> Self::Output {
+        -self.clone()
+    }
+}
+
+impl Add<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, rhs: &BigInt) -> Self::Output {
+        if self.negative == rhs.negative {
+            return BigInt::from_sign_magnitude(self.negative, &self.magnitude + &rhs.magnitude);
+        }
+
+        match self.magnitude.cmp(&rhs.magnitude) {
+            Ordering::Less => {
+                BigInt::from_sign_magnitude(rhs.negative, &rhs.magnitude - &self.magnitude)
+            }
+            _ => BigInt::from_sign_magnitude(self.negative, &self.magnitude - &rhs.magnitude),
+        }
+    }
+}
+
+impl Sub<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, rhs: &BigInt) -> Self::Output {
+        self + &-rhs
+    }
+}
+
+impl Mul<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, rhs: &BigInt) -> Self::Output {
+        BigInt::from_sign_magnitude(
+            self.negative != rhs.negative,
+            &self.magnitude * &rhs.magnitude,
+        )
+    }
+}
+
+impl Div<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn div(self, rhs: &BigInt) -> Self::Output {
+        self.div_rem(rhs).0
+    }
+}
+
+impl Rem<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn rem(self, rhs: &BigInt) -> Self::Output {
+        self.div_rem(rhs).1
+    }
+}
+
+forward_binop!(BigInt, Add, add, AddAssign, add_assign);
+forward_binop!(BigInt, Sub, sub, SubAssign, sub_assign);
+forward_binop!(BigInt, Mul, mul, MulAssign, mul_assign);
+forward_binop!(BigInt, Div, div, DivAssign, div_assign);
+forward_binop!(BigInt, Rem, rem, RemAssign, rem_assign);
+forward_primitive_binop!(BigInt, Add, add; i64);
+forward_primitive_binop!(BigInt, Sub, sub; i64);
+forward_primitive_binop!(BigInt, Mul, mul; i64);
+forward_primitive_binop!(BigInt, Div, div; i64);
+forward_primitive_binop!(BigInt, Rem, rem; i64);
+
+impl Shl<u64> for &BigInt {
+    type Output = BigInt;
+
+    fn shl(self, rhs: u64) -> Self::Output {
+        BigInt::from_sign_magnitude(self.negative, &self.magnitude << rhs)
+    }
+}
+
+impl Shr<u64> for &BigInt {
+    type Output = BigInt;
+
+    fn shr(self, rhs: u64) -> Self::Output {
+        let shifted = &self.magnitude >> rhs;
+
+        // round towards negative infinity if any of the dropped bits is set
+        if self.negative && self.magnitude.trailing_zeros() < Some(rhs) {
+            return BigInt::from_sign_magnitude(true, shifted + 1u64);
+        }
+
+        BigInt::from_sign_magnitude(self.negative, shifted)
+    }
+}
+
+impl Shl<u64> for BigInt {
+    type Output = BigInt;
+
+    fn shl(self, rhs: u64) -> Self::Output {
+        &self << rhs
+    }
+}
+
+impl Shr<u64> for BigInt {
+    type Output = BigInt;
+
+    fn shr(self, rhs: u64) -> Self::Output {
+        &self >> rhs
+    }
+}
+
+impl ShlAssign<u64> for BigInt {
+    fn shl_assign(&mut self, rhs: u64) {
+        *self = &*self << rhs;
+    }
+}
+
+impl ShrAssign<u64> for BigInt {
+    fn shr_assign(&mut self, rhs: u64) {
+        *self = &*self >> rhs;
+    }
+}
+
+impl Sum for BigInt {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, e| acc + e)
+    }
+}
+
+impl Product for BigInt {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::from(1), |acc, e| acc * e)
+    }
+}
+
+impl Debug for BigInt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for BigInt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad_integral(!self.negative, "", &self.magnitude.to_str_radix(10))
+    }
+}
+
+/// Parses a decimal number, an optional `+` or `-` sign is allowed.
+impl FromStr for BigInt {
+    type Err = ParseBigIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_radix(s, 10)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v1::math::extended_gcd_signed;
+    use crate::v1::math::prime::SplitMix64;
+
+    #[test]
+    fn arithmetic_matches_i128() {
+        let mut rng = SplitMix64(42);
+
+        for _ in 0..1000 {
+            let a = rng.next() as i64 as i128;
+            let b = (rng.next() as i64 >> (rng.next() % 64)) as i128;
+            let (x, y) = (BigInt::from(a), BigInt::from(b));
+
+            assert_eq!(Some(a + b), (&x + &y).to_i128());
+            assert_eq!(Some(a - b), (&x - &y).to_i128());
+            assert_eq!(Some(a * b), (&x * &y).to_i128());
+            assert_eq!(a.cmp(&b), x.cmp(&y));
+            assert_eq!(Some(a >> 7), (&x >> 7).to_i128());
+            assert_eq!(Some(a << 40), (&x << 40).to_i128());
+
+            if b != 0 {
+                assert_eq!(Some(a / b), (&x / &y).to_i128());
+                assert_eq!(Some(a % b), (&x % &y).to_i128());
+                assert_eq!(Some(a.rem_euclid(b)), x.rem_euclid(&y).to_i128());
+            }
+        }
+    }
+
+    #[test]
+    fn conversions_work() {
+        assert_eq!(Some(i128::MIN), BigInt::from(i128::MIN).to_i128());
+        assert_eq!(None, (BigInt::from(i128::MIN) - 1).to_i128());
+        assert_eq!(Some(i64::MIN), BigInt::from(i64::MIN).to_i64());
+        assert_eq!(None, BigInt::from(i64::MAX as i128 + 1).to_i64());
+        assert_eq!(
+            (-1, 0, 1),
+            (
+                BigInt::from(-7).signum(),
+                BigInt::ZERO.signum(),
+                BigInt::from(7).signum()
+            )
+        );
+        assert_eq!(BigInt::from(-8), BigInt::from(-2).pow(3));
+    }
+
+    #[test]
+    fn parse_and_format_works() {
+        // arrange
+        let test_suits = [
+            // negative
+            ("-123", Ok(BigInt::from(-123))),
+            // positive with sign
+            ("+123", Ok(BigInt::from(123))),
+            // negative zero
+            ("-0", Ok(BigInt::ZERO)),
+            // double sign
+            ("-+1", Err(ParseBigIntError::InvalidDigit)),
+            // no digits
+            ("-", Err(ParseBigIntError::Empty)),
+        ];
+
+        // act
+        let result: Vec<Result<BigInt, ParseBigIntError>> =
+            test_suits.iter().map(|t| t.0.parse()).collect();
+
+        // assert
+        for i in 0..test_suits.len() {
+            assert_eq!(test_suits[i].1, result[i]);
+        }
+
+        assert_eq!("-ff", BigInt::from(-255).to_str_radix(16));
+        assert_eq!("-0042", format!("{:05}", BigInt::from(-42)));
+        assert_eq!("+42", format!("{:+}", BigInt::from(42)));
+    }
+
+    #[test]
+    fn extended_gcd_matches_signed() {
+        let mut rng = SplitMix64(42);
+
+        for _ in 0..1000 {
+            let (a, b) = (rng.next() as i64 >> (rng.next() % 64), rng.next() as i64);
+            let (gcd, x, y) = extended_gcd_signed(a, b);
+
+            assert_eq!(
+                (BigUint::from(gcd), BigInt::from(x), BigInt::from(y)),
+                BigInt::from(a).extended_gcd(&BigInt::from(b)),
+                "a = {a}, b = {b}"
+            );
+        }
+    }
+}
@@ -0,0 +1,1172 @@
+use crate::v1::math::{gcd, BigInt};
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::iter::{Product, Sum};
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign,
+    Sub, SubAssign,
+};
+use std::str::FromStr;
+
+/// Operands' length (in 64-bit limbs) starting from which Karatsuba's multiplication is used.
+const KARATSUBA_THRESHOLD: usize = 32;
+
+/// An arbitrary precision unsigned integer.
+///
+/// The number is stored as little-endian 64-bit limbs without leading zeros, so equal numbers
+/// have equal representations and the derived `Eq` and `Hash` are consistent with the value.
+/// Operators are implemented for both owned values and references.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::BigUint;
+///
+/// let a: BigUint = "340282366920938463463374607431768211457".parse().unwrap();
+/// let b = BigUint::from(u64::MAX);
+///
+/// assert_eq!("340282366920938463481821351505477763072", (&a + &b).to_string());
+/// assert_eq!("6277101735386680763495507056286727952657427581105975853055", (&a * &b).to_string());
+/// assert_eq!((BigUint::from(18446744073709551617u128), BigUint::from(2u64)), a.div_rem(&b));
+/// assert_eq!(BigUint::from(1u64) << 128, &a - 1u64);
+/// assert_eq!("10000000000000000", (a >> 64).to_str_radix(16));
+/// assert_eq!(BigUint::from(3u64), BigUint::from(12u64).gcd(&BigUint::from(9u64)));
+/// ```
+/// ## Corner cases
+/// - Zero is represented with no limbs.
+/// - Subtraction panics on underflow, use [`BigUint::checked_sub`] to handle it.
+/// ```
+/// use ads_rs::prelude::v1::math::BigUint;
+///
+/// assert_eq!(BigUint::ZERO, BigUint::from(0u64));
+/// assert_eq!(None, BigUint::from(1u64).checked_sub(&BigUint::from(2u64)));
+/// assert_eq!("0", BigUint::ZERO.to_string());
+/// ```
+/// # Panics
+/// Division by zero and subtraction underflow panic.
+/// # Implementation details
+/// - Multiplication is schoolbook below 32 limbs and Karatsuba's above it,
+///   unbalanced operands are split into chunks of the shorter one's length.
+/// - Division is Knuth's Algorithm D with a 128-bit estimation of every quotient limb.
+/// - GCD is Lehmer's algorithm, which simulates Euclid's steps on the leading 64 bits,
+///   the last single limb is finished with Stein's algorithm (from [`gcd`]).
+/// - Parsing and formatting process as many digits per limb operation as fit into `u64`.
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    limbs: Vec<u64>,
+}
+
+impl BigUint {
+    /// The number 0.
+    pub const ZERO: Self = Self { limbs: Vec::new() };
+
+    fn from_limbs(mut limbs: Vec<u64>) -> Self {
+        trim(&mut limbs);
+
+        Self { limbs }
+    }
+
+    /// Checks whether the number equals zero.
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// Returns the number of bits required to represent the number, 0 for zero.
+    pub fn bits(&self) -> u64 {
+        match self.limbs.last() {
+            Some(&top) => self.limbs.len() as u64 * 64 - top.leading_zeros() as u64,
+            None => 0,
+        }
+    }
+
+    /// Returns the number of trailing zero bits, `None` for zero.
+    pub fn trailing_zeros(&self) -> Option<u64> {
+        let i = self.limbs.iter().position(|&limb| limb != 0)?;
+
+        Some(i as u64 * 64 + self.limbs[i].trailing_zeros() as u64)
+    }
+
+    /// Converts the number into `u64`, `None` if it doesn't fit.
+    pub fn to_u64(&self) -> Option<u64> {
+        match self.limbs[..] {
+            [] => Some(0),
+            [lo] => Some(lo),
+            _ => None,
+        }
+    }
+
+    /// Converts the number into `u128`, `None` if it doesn't fit.
+    pub fn to_u128(&self) -> Option<u128> {
+        match self.limbs[..] {
+            [] => Some(0),
+            [lo] => Some(lo as u128),
+            [lo, hi] => Some((hi as u128) << 64 | lo as u128),
+            _ => None,
+        }
+    }
+
+    /// Finds `self - rhs`, `None` if the result is negative.
+    pub fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        if *self < *rhs {
+            return None;
+        }
+
+        let mut limbs = self.limbs.clone();
+        sub_assign_limbs(&mut limbs, &rhs.limbs);
+
+        Some(Self::from_limbs(limbs))
+    }
+
+    /// Finds the quotient and the remainder of the division by `rhs`.
+    /// # Panics
+    /// Panics if `rhs` equals zero.
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        assert!(!rhs.is_zero(), "attempt to divide by zero");
+
+        if *self < *rhs {
+            return (Self::ZERO, self.clone());
+        }
+
+        if rhs.limbs.len() == 1 {
+            let (quot, rem) = div_rem_limb(&self.limbs, rhs.limbs[0]);
+
+            return (Self::from_limbs(quot), Self::from(rem));
+        }
+
+        let (quot, rem) = div_rem_limbs(&self.limbs, &rhs.limbs);
+
+        (Self::from_limbs(quot), Self::from_limbs(rem))
+    }
+
+    /// Raises the number to the power of `exp`, 0<sup>0</sup> equals 1.
+    pub fn pow(&self, mut exp: u32) -> Self {
+        let mut base = self.clone();
+        let mut res = Self::from(1u64);
+
+        while exp > 0 {
+            if exp & 1 == 1 {
+                res = &res * &base;
+            }
+
+            exp >>= 1;
+
+            if exp > 0 {
+                base = &base * &base;
+            }
+        }
+
+        res
+    }
+
+    /// Finds `self`<sup>`exp`</sup> `mod m`, 0<sup>0</sup> equals 1 (modulo `m`).
+    /// # Panics
+    /// Panics if `m` equals zero.
+    pub fn mod_pow(&self, exp: &Self, m: &Self) -> Self {
+        assert!(!m.is_zero(), "modulus must be positive");
+
+        let base = self % m;
+        let mut res = Self::from(1u64) % m;
+
+        for i in (0..exp.bits()).rev() {
+            res = &(&res * &res) % m;
+
+            if exp.bit(i) {
+                res = &(&res * &base) % m;
+            }
+        }
+
+        res
+    }
+
+    /// Finds a GCD (Greatest Common Divisor) of `self` and `rhs`, GCD of two zeros equals 0.
+    pub fn gcd(&self, rhs: &Self) -> Self {
+        let (mut a, mut b) = (self.clone(), rhs.clone());
+
+        while b.limbs.len() > 1 {
+            (a, b) = match lehmer_matrix(&a, &b) {
+                Some(m) => (combine(&a, &b, m[0], m[1]), combine(&a, &b, m[2], m[3])),
+                None => (b.clone(), &a % &b),
+            };
+        }
+
+        match b.to_u64() {
+            Some(0) => a,
+            Some(b) => Self::from(gcd((&a % b).to_u64().unwrap(), b)),
+            None => unreachable!(),
+        }
+    }
+
+    /// Finds an extended GCD (Greatest Common Divisor) of `self` and `rhs`: a tuple `(gcd, x, y)` such that
+    ///
+    /// x * self + y * rhs = gcd(self, rhs)
+    ///
+    /// Zeros are handled like in [`extended_gcd`](crate::v1::math::extended_gcd):
+    /// `(0, 0)` gives `(0, 1, 0)` and `(0, b)` gives `(b, 0, 1)`.
+    pub fn extended_gcd(&self, rhs: &Self) -> (Self, BigInt, BigInt) {
+        let (mut a, mut b) = (self.clone(), rhs.clone());
+        let (mut x0, mut x1) = (BigInt::from(1), BigInt::ZERO);
+
+        while !b.is_zero() {
+            match lehmer_matrix(&a, &b) {
+                Some(m) => {
+                    (a, b) = (combine(&a, &b, m[0], m[1]), combine(&a, &b, m[2], m[3]));
+                    (x0, x1) = (
+                        BigInt::from(m[0]) * &x0 + BigInt::from(m[1]) * &x1,
+                        BigInt::from(m[2]) * &x0 + BigInt::from(m[3]) * &x1,
+                    );
+                }
+                None => {
+                    let (quot, rem) = a.div_rem(&b);
+                    let next = &x0 - BigInt::from(quot) * &x1;
+
+                    (a, b) = (b, rem);
+                    (x0, x1) = (x1, next);
+                }
+            }
+        }
+
+        let y = if rhs.is_zero() {
+            BigInt::ZERO
+        } else {
+            (BigInt::from(a.clone()) - &x0 * BigInt::from(self.clone())) / BigInt::from(rhs.clone())
+        };
+
+        (a, x0, y)
+    }
+
+    /// Finds an LCM (Least Common Multiple) of `self` and `rhs`, LCM with zero equals 0.
+    pub fn lcm(&self, rhs: &Self) -> Self {
+        if self.is_zero() || rhs.is_zero() {
+            return Self::ZERO;
+        }
+
+        &(self / &self.gcd(rhs)) * rhs
+    }
+
+    /// Finds the multiplicative inverse modulo `m`, `None` if `self` and `m` aren't coprime.
+    /// Like [`mod_inv`](crate::v1::math::mod_inv), everything is invertible modulo 1 with the inverse 0.
+    /// # Panics
+    /// Panics if `m` equals zero.
+    pub fn mod_inv(&self, m: &Self) -> Option<Self> {
+        let (gcd, x, _) = (self % m).extended_gcd(m);
+
+        if gcd != Self::from(1u64) {
+            return None;
+        }
+
+        Some(x.rem_euclid(&BigInt::from(m.clone())).unsigned_abs())
+    }
+
+    /// Parses a number in the given radix, an optional `+` sign is allowed.
+    /// # Panics
+    /// Panics if `radix` isn't in the range `[2, 36]`.
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseBigIntError> {
+        assert!(
+            (2..=36).contains(&radix),
+            "radix must lie in the range [2, 36]"
+        );
+
+        let digits = s.strip_prefix('+').unwrap_or(s).as_bytes();
+
+        if digits.is_empty() {
+            return Err(ParseBigIntError::Empty);
+        }
+
+        let (_, chunk_len) = chunk_base(radix);
+        let mut limbs = Vec::new();
+
+        for chunk in digits.chunks(chunk_len) {
+            let mut value = 0u64;
+
+            for &digit in chunk {
+                let digit = (digit as char)
+                    .to_digit(radix)
+                    .ok_or(ParseBigIntError::InvalidDigit)?;
+                value = value * radix as u64 + digit as u64;
+            }
+
+            mul_add_limb(&mut limbs, (radix as u64).pow(chunk.len() as u32), value);
+        }
+
+        Ok(Self::from_limbs(limbs))
+    }
+
+    /// Formats the number in the given radix with lowercase digits.
+    /// # Panics
+    /// Panics if `radix` isn't in the range `[2, 36]`.
+    pub fn to_str_radix(&self, radix: u32) -> String {
+        assert!(
+            (2..=36).contains(&radix),
+            "radix must lie in the range [2, 36]"
+        );
+
+        if self.is_zero() {
+            return "0".to_string();
+        }
+
+        let (base, chunk_len) = chunk_base(radix);
+        let mut chunks = Vec::new();
+        let mut limbs = self.limbs.clone();
+
+        while !limbs.is_empty() {
+            let (quot, rem) = div_rem_limb(&limbs, base);
+            chunks.push(rem);
+            limbs = quot;
+            trim(&mut limbs);
+        }
+
+        let mut res = String::new();
+
+        for (i, &chunk) in chunks.iter().rev().enumerate() {
+            let mut digits = Vec::with_capacity(chunk_len);
+            let mut value = chunk;
+
+            while value > 0 || (i > 0 && digits.len() < chunk_len) || digits.is_empty() {
+                digits.push(std::char::from_digit((value % radix as u64) as u32, radix).unwrap());
+                value /= radix as u64;
+            }
+
+            res.extend(digits.iter().rev());
+        }
+
+        res
+    }
+
+    fn bit(&self, i: u64) -> bool {
+        self.limbs[(i / 64) as usize] >> (i % 64) & 1 == 1
+    }
+}
+
+/// Finds the largest power of `radix` fitting into `u64` and its exponent.
+fn chunk_base(radix: u32) -> (u64, usize) {
+    let (mut base, mut len) = (radix as u64, 1);
+
+    while let Some(next) = base.checked_mul(radix as u64) {
+        base = next;
+        len += 1;
+    }
+
+    (base, len)
+}
+
+/// Simulates Euclid's algorithm on the leading 64 bits of `a` and `b` (Knuth's Algorithm L).
+/// Returns the matrix `[A, B, C, D]` such that `(A * a + B * b, C * a + D * b)` is a pair of the
+/// remainder sequence, or `None` if a single step can't be predicted and a full division is required.
+fn lehmer_matrix(a: &BigUint, b: &BigUint) -> Option<[i128; 4]> {
+    if b.limbs.len() < 2 || a.limbs.len() != b.limbs.len() || a < b {
+        return None;
+    }
+
+    let shift = a.bits() - 64;
+    let mut x = (a >> shift).to_u64().unwrap() as i128;
+    let mut y = (b >> shift).to_u64().unwrap() as i128;
+    let (mut aa, mut bb, mut cc, mut dd) = (1i128, 0i128, 0i128, 1i128);
+
+    while y + cc != 0 && y + dd != 0 {
+        let q = (x + aa) / (y + cc);
+
+        if q != (x + bb) / (y + dd) {
+            break;
+        }
+
+        (aa, cc) = (cc, aa - q * cc);
+        (bb, dd) = (dd, bb - q * dd);
+        (x, y) = (y, x - q * y);
+    }
+
+    if bb == 0 {
+        return None;
+    }
+
+    Some([aa, bb, cc, dd])
+}
+
+/// Finds `p * a + q * b`, which is known to be non-negative, `p` and `q` fit into 64 bits.
+fn combine(a: &BigUint, b: &BigUint, p: i128, q: i128) -> BigUint {
+    let pa = a * BigUint::from(p.unsigned_abs());
+    let qb = b * BigUint::from(q.unsigned_abs());
+
+    match (p < 0, q < 0) {
+        (false, false) => pa + qb,
+        (false, true) => pa - qb,
+        (true, false) => qb - pa,
+        (true, true) => unreachable!(),
+    }
+}
+
+fn trim(limbs: &mut Vec<u64>) {
+    while limbs.last() == Some(&0) {
+        limbs.pop();
+    }
+}
+
+/// Adds `rhs` to `lhs` starting from the limb `offset`, `lhs` must be long enough to hold the sum.
+fn add_assign_limbs(lhs: &mut [u64], rhs: &[u64], offset: usize) {
+    let mut carry = false;
+
+    for (i, &limb) in rhs.iter().enumerate() {
+        let (sum, c0) = lhs[offset + i].overflowing_add(limb);
+        let (sum, c1) = sum.overflowing_add(carry as u64);
+        lhs[offset + i] = sum;
+        carry = c0 || c1;
+    }
+
+    let mut i = offset + rhs.len();
+
+    while carry {
+        let (sum, c) = lhs[i].overflowing_add(1);
+        lhs[i] = sum;
+        carry = c;
+        i += 1;
+    }
+}
+
+/// Subtracts `rhs` from `lhs` in place, `lhs` must be not less than `rhs`.
+fn sub_assign_limbs(lhs: &mut [u64], rhs: &[u64]) {
+    let mut borrow = false;
+
+    for (i, limb) in lhs.iter_mut().enumerate() {
+        if i >= rhs.len() && !borrow {
+            break;
+        }
+
+        let (diff, b0) = limb.overflowing_sub(rhs.get(i).copied().unwrap_or(0));
+        let (diff, b1) = diff.overflowing_sub(borrow as u64);
+        *limb = diff;
+        borrow = b0 || b1;
+    }
+}
+
+fn add_limbs(lhs: &[u64], rhs: &[u64]) -> Vec<u64> {
+    let (long, short) = if lhs.len() >= rhs.len() {
+        (lhs, rhs)
+    } else {
+        (rhs, lhs)
+    };
+    let mut res = long.to_vec();
+    res.push(0);
+    add_assign_limbs(&mut res, short, 0);
+    trim(&mut res);
+
+    res
+}
+
+/// Replaces `limbs` with `limbs * mul + add`.
+fn mul_add_limb(limbs: &mut Vec<u64>, mul: u64, add: u64) {
+    let mut carry = add;
+
+    for limb in limbs.iter_mut() {
+        let t = *limb as u128 * mul as u128 + carry as u128;
+        *limb = t as u64;
+        carry = (t >> 64) as u64;
+    }
+
+    if carry > 0 {
+        limbs.push(carry);
+    }
+}
+
+fn mul_limbs(lhs: &[u64], rhs: &[u64]) -> Vec<u64> {
+    let (long, short) = if lhs.len() >= rhs.len() {
+        (lhs, rhs)
+    } else {
+        (rhs, lhs)
+    };
+
+    if short.is_empty() {
+        return Vec::new();
+    }
+
+    if short.len() < KARATSUBA_THRESHOLD {
+        return mul_schoolbook(long, short);
+    }
+
+    let mut res = vec![0; long.len() + short.len()];
+
+    if long.len() >= 2 * short.len() {
+        for (i, chunk) in long.chunks(short.len()).enumerate() {
+            let mut prod = mul_limbs(chunk, short);
+            trim(&mut prod);
+            add_assign_limbs(&mut res, &prod, i * short.len());
+        }
+
+        return res;
+    }
+
+    // (a1 * B + a0) * (b1 * B + b0) = z2 * B^2 + (z1 - z2 - z0) * B + z0
+    let half = long.len() / 2;
+    let (a0, a1) = long.split_at(half);
+    let (b0, b1) = short.split_at(half);
+
+    let mut z0 = mul_limbs(a0, b0);
+    let mut z2 = mul_limbs(a1, b1);
+    let mut z1 = mul_limbs(&add_limbs(a0, a1), &add_limbs(b0, b1));
+    trim(&mut z0);
+    trim(&mut z2);
+    sub_assign_limbs(&mut z1, &z0);
+    sub_assign_limbs(&mut z1, &z2);
+    trim(&mut z1);
+
+    add_assign_limbs(&mut res, &z0, 0);
+    add_assign_limbs(&mut res, &z1, half);
+    add_assign_limbs(&mut res, &z2, 2 * half);
+
+    res
+}
+
+fn mul_schoolbook(lhs: &[u64], rhs: &[u64]) -> Vec<u64> {
+    let mut res = vec![0; lhs.len() + rhs.len()];
+
+    for (i, &a) in lhs.iter().enumerate() {
+        let mut carry = 0u64;
+
+        for (j, &b) in rhs.iter().enumerate() {
+            let t = a as u128 * b as u128 + res[i + j] as u128 + carry as u128;
+            res[i + j] = t as u64;
+            carry = (t >> 64) as u64;
+        }
+
+        res[i + rhs.len()] = carry;
+    }
+
+    res
+}
+
+/// Divides `limbs` by a single limb, returns the (untrimmed) quotient and the remainder.
+fn div_rem_limb(limbs: &[u64], divisor: u64) -> (Vec<u64>, u64) {
+    let mut quot = vec![0; limbs.len()];
+    let mut rem = 0u64;
+
+    for (i, &limb) in limbs.iter().enumerate().rev() {
+        let cur = (rem as u128) << 64 | limb as u128;
+        quot[i] = (cur / divisor as u128) as u64;
+        rem = (cur % divisor as u128) as u64;
+    }
+
+    (quot, rem)
+}
+
+/// Knuth's Algorithm D, `divisor` has at least 2 limbs and `dividend >= divisor`.
+fn div_rem_limbs(dividend: &[u64], divisor: &[u64]) -> (Vec<u64>, Vec<u64>) {
+    // normalize, so the top bit of the divisor is set
+    let shift = divisor.last().unwrap().leading_zeros() as u64;
+    let v = shl_limbs(divisor, shift);
+    let mut u = shl_limbs(dividend, shift);
+    u.resize(dividend.len() + 1, 0);
+
+    let n = v.len();
+    let (v1, v0) = (v[n - 1] as u128, v[n - 2] as u128);
+    let mut quot = vec![0; u.len() - n];
+
+    for j in (0..quot.len()).rev() {
+        let top = (u[j + n] as u128) << 64 | u[j + n - 1] as u128;
+        let (mut q, mut r) = (top / v1, top % v1);
+
+        while q > u64::MAX as u128 || q * v0 > (r << 64 | u[j + n - 2] as u128) {
+            q -= 1;
+            r += v1;
+
+            if r > u64::MAX as u128 {
+                break;
+            }
+        }
+
+        // u[j..=j + n] -= q * v
+        let (mut carry, mut borrow) = (0u64, false);
+
+        for i in 0..=n {
+            let p = q * v.get(i).copied().unwrap_or(0) as u128 + carry as u128;
+            carry = (p >> 64) as u64;
+            let (diff, b0) = u[i + j].overflowing_sub(p as u64);
+            let (diff, b1) = diff.overflowing_sub(borrow as u64);
+            u[i + j] = diff;
+            borrow = b0 || b1;
+        }
+
+        // the estimation was one too large, add the divisor back
+        if borrow {
+            q -= 1;
+            let mut carry = false;
+
+            for i in 0..n {
+                let (sum, c0) = u[i + j].overflowing_add(v[i]);
+                let (sum, c1) = sum.overflowing_add(carry as u64);
+                u[i + j] = sum;
+                carry = c0 || c1;
+            }
+
+            u[j + n] = u[j + n].wrapping_add(carry as u64);
+        }
+
+        quot[j] = q as u64;
+    }
+
+    u.truncate(n);
+
+    (quot, shr_limbs(&u, shift))
+}
+
+/// Shifts `limbs` left by `shift < 64` bits, the top limb is dropped if it becomes zero.
+fn shl_limbs(limbs: &[u64], shift: u64) -> Vec<u64> {
+    let mut res = Vec::with_capacity(limbs.len() + 1);
+    let mut carry = 0;
+
+    for &limb in limbs {
+        res.push(limb << shift | carry);
+        carry = if shift == 0 { 0 } else { limb >> (64 - shift) };
+    }
+
+    if carry > 0 {
+        res.push(carry);
+    }
+
+    res
+}
+
+/// Shifts `limbs` right by `shift < 64` bits.
+fn shr_limbs(limbs: &[u64], shift: u64) -> Vec<u64> {
+    let mut res = vec![0; limbs.len()];
+
+    for (i, &limb) in limbs.iter().enumerate() {
+        res[i] = limb >> shift;
+
+        if shift > 0 && i + 1 < limbs.len() {
+            res[i] |= limbs[i + 1] << (64 - shift);
+        }
+    }
+
+    res
+}
+
+macro_rules! impl_from_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for BigUint {
+                fn from(value: $t) -> Self {
+                    Self::from(value as u128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_unsigned!(u8, u16, u32, u64, usize);
+
+impl From<u128> for BigUint {
+    fn from(value: u128) -> Self {
+        Self::from_limbs(vec![value as u64, (value >> 64) as u64])
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Implements an operator for all combinations of owned and borrowed operands,
+/// and its assigning version, given the implementation for `&T op &T`.
+macro_rules! forward_binop {
+    ($t:ty, $imp:ident, $method:ident, $imp_assign:ident, $method_assign:ident) => {
+        impl $imp<$t> for $t {
+            type Output = $t;
+
+            fn $method(self, rhs: $t) -> Self::Output {
+                (&self).$method(&rhs)
+            }
+        }
+
+        impl $imp<&$t> for $t {
+            type Output = $t;
+
+            fn $method(self, rhs: &$t) -> Self::Output {
+                (&self).$method(rhs)
+            }
+        }
+
+        impl $imp<$t> for &$t {
+            type Output = $t;
+
+            fn $method(self, rhs: $t) -> Self::Output {
+                self.$method(&rhs)
+            }
+        }
+
+        impl $imp_assign<$t> for $t {
+            fn $method_assign(&mut self, rhs: $t) {
+                *self = (&*self).$method(&rhs);
+            }
+        }
+
+        impl $imp_assign<&$t> for $t {
+            fn $method_assign(&mut self, rhs: &$t) {
+                *self = (&*self).$method(rhs);
+            }
+        }
+    };
+}
+
+pub(crate) use forward_binop;
+
+/// Implements an operator with a primitive right operand by converting it into `$t`.
+macro_rules! forward_primitive_binop {
+    ($t:ty, $imp:ident, $method:ident; $($p:ty),*) => {
+        $(
+            impl $imp<$p> for $t {
+                type Output = $t;
+
+                fn $method(self, rhs: $p) -> Self::Output {
+                    (&self).$method(&<$t>::from(rhs))
+                }
+            }
+
+            impl $imp<$p> for &$t {
+                type Output = $t;
+
+                fn $method(self, rhs: $p) -> Self::Output {
+                    self.$method(&<$t>::from(rhs))
+                }
+            }
+        )*
+    };
+}
+
+pub(crate) use forward_primitive_binop;
+
+impl Add<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn add(self, rhs: &BigUint) -> Self::Output {
+        BigUint::from_limbs(add_limbs(&self.limbs, &rhs.limbs))
+    }
+}
+
+impl Sub<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn sub(self, rhs: &BigUint) -> Self::Output {
+        self.checked_sub(rhs)
+            .expect("attempt to subtract with overflow")
+    }
+}
+
+impl Mul<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn mul(self, rhs: &BigUint) -> Self::Output {
+        BigUint::from_limbs(mul_limbs(&self.limbs, &rhs.limbs))
+    }
+}
+
+impl Div<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn div(self, rhs: &BigUint) -> Self::Output {
+        self.div_rem(rhs).0
+    }
+}
+
+impl Rem<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn rem(self, rhs: &BigUint) -> Self::Output {
+        self.div_rem(rhs).1
+    }
+}
+
+forward_binop!(BigUint, Add, add, AddAssign, add_assign);
+forward_binop!(BigUint, Sub, sub, SubAssign, sub_assign);
+forward_binop!(BigUint, Mul, mul, MulAssign, mul_assign);
+forward_binop!(BigUint, Div, div, DivAssign, div_assign);
+forward_binop!(BigUint, Rem, rem, RemAssign, rem_assign);
+forward_primitive_binop!(BigUint, Add, add; u64);
+forward_primitive_binop!(BigUint, Sub, sub; u64);
+forward_primitive_binop!(BigUint, Mul, mul; u64);
+forward_primitive_binop!(BigUint, Div, div; u64);
+forward_primitive_binop!(BigUint, Rem, rem; u64);
+
+impl Shl<u64> for &BigUint {
+    type Output = BigUint;
+
+    fn shl(self, rhs: u64) -> Self::Output {
+        if self.is_zero() {
+            return BigUint::ZERO;
+        }
+
+        let mut limbs = vec![0; (rhs / 64) as usize];
+        limbs.extend(shl_limbs(&self.limbs, rhs % 64));
+
+        BigUint::from_limbs(limbs)
+    }
+}
+
+impl Shr<u64> for &BigUint {
+    type Output = BigUint;
+
+    fn shr(self, rhs: u64) -> Self::Output {
+        let skip = (rhs / 64) as usize;
+
+        if skip >= self.limbs.len() {
+            return BigUint::ZERO;
+        }
+
+        BigUint::from_limbs(shr_limbs(&self.limbs[skip..], rhs % 64))
+    }
+}
+
+impl Shl<u64> for BigUint {
+    type Output = BigUint;
+
+    fn shl(self, rhs: u64) -> Self::Output {
+        &self << rhs
+    }
+}
+
+impl Shr<u64> for BigUint {
+    type Output = BigUint;
+
+    fn shr(self, rhs: u64) -> Self::Output {
+        &self >> rhs
+    }
+}
+
+impl ShlAssign<u64> for BigUint {
+    fn shl_assign(&mut self, rhs: u64) {
+        *self = &*self << rhs;
+    }
+}
+
+impl ShrAssign<u64> for BigUint {
+    fn shr_assign(&mut self, rhs: u64) {
+        *self = &*self >> rhs;
+    }
+}
+
+impl Sum for BigUint {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, e| acc + e)
+    }
+}
+
+impl Product for BigUint {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::from(1u64), |acc, e| acc * e)
+    }
+}
+
+impl Debug for BigUint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for BigUint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad_integral(true, "", &self.to_str_radix(10))
+    }
+}
+
+/// An error which can be returned when parsing a [`BigUint`] or a [`BigInt`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseBigIntError {
+    /// The string has no digits.
+    Empty,
+    /// The string contains a character which isn't a digit in the radix.
+    InvalidDigit,
+}
+
+impl Display for ParseBigIntError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "cannot parse integer from empty string"),
+            Self::InvalidDigit => write!(f, "invalid digit found in string"),
+        }
+    }
+}
+
+impl Error for ParseBigIntError {}
+
+/// Parses a decimal number, an optional `+` sign is allowed.
+impl FromStr for BigUint {
+    type Err = ParseBigIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_radix(s, 10)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v1::math::extended_gcd_wide;
+    use crate::v1::math::prime::SplitMix64;
+
+    fn random(rng: &mut SplitMix64, limbs: usize) -> BigUint {
+        BigUint::from_limbs((0..limbs).map(|_| rng.next()).collect())
+    }
+
+    fn random_up_to(rng: &mut SplitMix64, max_limbs: u64) -> BigUint {
+        let limbs = 1 + rng.next() % max_limbs;
+
+        random(rng, limbs as usize)
+    }
+
+    fn euclid(mut a: BigUint, mut b: BigUint) -> BigUint {
+        while !b.is_zero() {
+            (a, b) = (b.clone(), &a % &b);
+        }
+
+        a
+    }
+
+    #[test]
+    fn arithmetic_matches_u128() {
+        let mut rng = SplitMix64(42);
+
+        for _ in 0..1000 {
+            let (a, b) = (
+                rng.next() as u128,
+                (rng.next() >> (rng.next() % 64)) as u128 + 1,
+            );
+            let (x, y) = (BigUint::from(a), BigUint::from(b));
+
+            assert_eq!(Some(a + b), (&x + &y).to_u128());
+            assert_eq!(Some(a * b), (&x * &y).to_u128());
+            assert_eq!(Some(a / b), (&x / &y).to_u128());
+            assert_eq!(Some(a % b), (&x % &y).to_u128());
+            assert_eq!(
+                a.checked_sub(b),
+                x.checked_sub(&y).map(|e| e.to_u128().unwrap())
+            );
+            assert_eq!(a.cmp(&b), x.cmp(&y));
+            assert_eq!(Some(a << 37), (&x << 37).to_u128());
+            assert_eq!(Some(a >> 13), (&x >> 13).to_u128());
+            assert_eq!(128 - a.leading_zeros() as u64, x.bits());
+        }
+    }
+
+    #[test]
+    fn karatsuba_matches_schoolbook() {
+        let mut rng = SplitMix64(42);
+
+        for (n, m) in [
+            (32, 32),
+            (33, 47),
+            (64, 31),
+            (100, 40),
+            (150, 150),
+            (257, 64),
+        ] {
+            let (a, b) = (random(&mut rng, n), random(&mut rng, m));
+
+            assert_eq!(
+                BigUint::from_limbs(mul_schoolbook(&a.limbs, &b.limbs)),
+                &a * &b,
+                "n = {n}, m = {m}"
+            );
+        }
+    }
+
+    #[test]
+    fn div_rem_works() {
+        let mut rng = SplitMix64(42);
+
+        for _ in 0..300 {
+            let a = random_up_to(&mut rng, 40);
+            let b = random_up_to(&mut rng, 20) >> (rng.next() % 64);
+
+            if b.is_zero() {
+                continue;
+            }
+
+            let (q, r) = a.div_rem(&b);
+
+            assert!(r < b);
+            assert_eq!(a, &q * &b + &r);
+            assert_eq!(q, (&a - &r) / &b);
+        }
+    }
+
+    #[test]
+    fn div_rem_corrects_estimation() {
+        // the top limbs of the dividend and the divisor coincide, so the first estimation is too large
+        let b = BigUint::from_limbs(vec![u64::MAX, 0, 1 << 63]);
+        let a = &b * BigUint::from_limbs(vec![u64::MAX, u64::MAX]) + BigUint::from(12345u64);
+
+        assert_eq!(
+            (
+                BigUint::from_limbs(vec![u64::MAX, u64::MAX]),
+                BigUint::from(12345u64)
+            ),
+            a.div_rem(&b)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn div_by_zero_panics() {
+        let _ = BigUint::from(1u64) / BigUint::ZERO;
+    }
+
+    #[test]
+    #[should_panic]
+    fn sub_underflow_panics() {
+        let _ = BigUint::from(1u64) - BigUint::from(2u64);
+    }
+
+    #[test]
+    fn parse_and_format_works() {
+        // arrange
+        let test_suits = [
+            // zero
+            ("0", 10, BigUint::ZERO),
+            // leading zeros and sign
+            ("+000123", 10, BigUint::from(123u64)),
+            // u128::MAX
+            (
+                "340282366920938463463374607431768211455",
+                10,
+                BigUint::from(u128::MAX),
+            ),
+            // hexadecimal
+            ("ffffffffffffffff", 16, BigUint::from(u64::MAX)),
+            // uppercase digits
+            ("ZZ", 36, BigUint::from(1295u64)),
+        ];
+
+        // act
+        let result: Vec<Result<BigUint, ParseBigIntError>> = test_suits
+            .iter()
+            .map(|t| BigUint::from_str_radix(t.0, t.1))
+            .collect();
+
+        // assert
+        for i in 0..test_suits.len() {
+            assert_eq!(Ok(test_suits[i].2.clone()), result[i]);
+        }
+
+        assert_eq!(Err(ParseBigIntError::Empty), "".parse::<BigUint>());
+        assert_eq!(Err(ParseBigIntError::Empty), "+".parse::<BigUint>());
+        assert_eq!(
+            Err(ParseBigIntError::InvalidDigit),
+            "12a".parse::<BigUint>()
+        );
+        assert_eq!(Err(ParseBigIntError::InvalidDigit), "-1".parse::<BigUint>());
+        assert_eq!(
+            "invalid digit found in string",
+            ParseBigIntError::InvalidDigit.to_string()
+        );
+        assert_eq!("00042", format!("{:05}", BigUint::from(42u64)));
+    }
+
+    #[test]
+    fn parse_format_roundtrip() {
+        let mut rng = SplitMix64(42);
+
+        for radix in 2..=36 {
+            let a = random_up_to(&mut rng, 10);
+            let s = a.to_str_radix(radix);
+
+            assert_eq!(Ok(a), BigUint::from_str_radix(&s, radix), "radix = {radix}");
+        }
+
+        let a = BigUint::from(10u64).pow(100);
+
+        assert_eq!(format!("1{}", "0".repeat(100)), a.to_string());
+    }
+
+    #[test]
+    fn gcd_works() {
+        let mut rng = SplitMix64(42);
+
+        for _ in 0..100 {
+            let c = random_up_to(&mut rng, 8);
+            let a = &c * random_up_to(&mut rng, 64);
+            let b = &c * random_up_to(&mut rng, 64);
+
+            assert_eq!(euclid(a.clone(), b.clone()), a.gcd(&b));
+        }
+
+        let a = BigUint::from(6u64);
+
+        assert_eq!(BigUint::ZERO, BigUint::ZERO.gcd(&BigUint::ZERO));
+        assert_eq!(a, BigUint::ZERO.gcd(&a));
+        assert_eq!(a, a.gcd(&BigUint::ZERO));
+        assert_eq!(BigUint::ZERO, BigUint::ZERO.lcm(&a));
+        assert_eq!(BigUint::from(30u64), a.lcm(&BigUint::from(15u64)));
+    }
+
+    #[test]
+    fn extended_gcd_matches_wide() {
+        let mut rng = SplitMix64(42);
+
+        for _ in 0..1000 {
+            let (a, b) = (
+                rng.next() >> (rng.next() % 64),
+                rng.next() >> (rng.next() % 64),
+            );
+            let (gcd, x, y) = extended_gcd_wide(a, b);
+
+            assert_eq!(
+                (BigUint::from(gcd), BigInt::from(x), BigInt::from(y)),
+                BigUint::from(a).extended_gcd(&BigUint::from(b)),
+                "a = {a}, b = {b}"
+            );
+        }
+
+        for (a, b) in [(0, 0), (0, 5), (5, 0)] {
+            let (gcd, x, y) = extended_gcd_wide(a, b);
+
+            assert_eq!(
+                (BigUint::from(gcd), BigInt::from(x), BigInt::from(y)),
+                BigUint::from(a).extended_gcd(&BigUint::from(b))
+            );
+        }
+    }
+
+    #[test]
+    fn extended_gcd_works() {
+        let mut rng = SplitMix64(42);
+
+        for _ in 0..50 {
+            let c = random_up_to(&mut rng, 4);
+            let a = &c * random_up_to(&mut rng, 64);
+            let b = &c * random_up_to(&mut rng, 64);
+            let (gcd, x, y) = a.extended_gcd(&b);
+
+            assert_eq!(a.gcd(&b), gcd);
+            assert_eq!(
+                BigInt::from(gcd),
+                &x * BigInt::from(a.clone()) + &y * BigInt::from(b.clone())
+            );
+        }
+    }
+
+    #[test]
+    fn mod_inv_works() {
+        let mut rng = SplitMix64(42);
+        let m = (BigUint::from(1u64) << 521) - 1u64;
+
+        for _ in 0..20 {
+            let a = random(&mut rng, 9) % &m;
+            let inv = a.mod_inv(&m).unwrap();
+
+            assert_eq!(BigUint::from(1u64), &a * &inv % &m);
+            assert_eq!(inv, a.mod_pow(&(&m - 2u64), &m));
+        }
+
+        assert_eq!(None, BigUint::from(6u64).mod_inv(&BigUint::from(9u64)));
+        assert_eq!(
+            Some(BigUint::ZERO),
+            BigUint::ZERO.mod_inv(&BigUint::from(1u64))
+        );
+    }
+}
@@ -0,0 +1,643 @@
+use crate::v1::math::{crt, factorize, gcd, mod_inv, mod_mul, mod_pow};
+
+/// Tables of factorials and inverse factorials modulo a prime `p`,
+/// which answer binomial coefficients, permutations and multinomial coefficients.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::Combinatorics;
+///
+/// let comb = Combinatorics::new(1000, 998_244_353);
+///
+/// assert_eq!(120, comb.binomial(10, 3));
+/// assert_eq!(720, comb.permutations(10, 3));
+/// assert_eq!(60, comb.multinomial(&[1, 2, 3]));
+/// assert_eq!(198_626_801, comb.binomial(100, 50));
+/// ```
+/// Arguments beyond the table are handled with Lucas' theorem, if the table covers all residues modulo `p`.
+/// ```
+/// use ads_rs::prelude::v1::math::Combinatorics;
+///
+/// let comb = Combinatorics::new(usize::MAX, 1_000_003);
+///
+/// assert_eq!(1_000_002, comb.limit());
+/// assert_eq!(596_118, comb.binomial(1_000_000_000_000_000_000, 12_345_678));
+/// assert_eq!(0, comb.binomial(1_000_000_000_000_000_000, 1_000_000_000));
+/// ```
+/// ## Corner cases
+/// - Binomial coefficients and permutations equal 0 if `k > n`.
+/// - Factorials of `p` and above are divisible by `p`, so the table stops at `p - 1`.
+/// ```
+/// use ads_rs::prelude::v1::math::Combinatorics;
+///
+/// let comb = Combinatorics::new(10, 7);
+///
+/// assert_eq!(6, comb.limit());
+/// assert_eq!(0, comb.binomial(3, 5));
+/// assert_eq!(0, comb.permutations(8, 3));
+/// assert_eq!(2, comb.permutations(9, 2));
+/// ```
+/// # Implementation details
+/// - Inverse factorials are found from the inverse of the largest factorial (from [`mod_inv`]) in a single backward pass.
+/// - Time and memory complexity is O(min(N, p)) for the tables, every query with arguments within the table takes O(1).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Combinatorics {
+    p: u64,
+    fact: Vec<u64>,
+    inv_fact: Vec<u64>,
+}
+
+impl Combinatorics {
+    /// Builds tables of factorials of numbers in `[0, min(n, p - 1)]` modulo a prime `p`.
+    /// # Panics
+    /// Panics if `p == 0` or `p` isn't prime and the largest factorial isn't invertible.
+    /// Primality of `p` isn't checked.
+    pub fn new(n: usize, p: u64) -> Self {
+        assert!(p != 0, "modulus equals 0");
+
+        let limit = n.min((p - 1).try_into().unwrap_or(usize::MAX));
+
+        let mut fact = vec![1 % p; limit + 1];
+        for i in 1..=limit {
+            fact[i] = mod_mul(fact[i - 1], i as u64, p);
+        }
+
+        let mut inv_fact = vec![0; limit + 1];
+        inv_fact[limit] = mod_inv(fact[limit], p).expect("modulus isn't prime");
+        for i in (1..=limit).rev() {
+            inv_fact[i - 1] = mod_mul(inv_fact[i], i as u64, p);
+        }
+
+        Self { p, fact, inv_fact }
+    }
+
+    /// Returns the modulus.
+    pub fn modulus(&self) -> u64 {
+        self.p
+    }
+
+    /// Returns the upper bound (inclusive) of the tables.
+    pub fn limit(&self) -> usize {
+        self.fact.len() - 1
+    }
+
+    /// Returns `k! mod p`.
+    /// # Panics
+    /// Panics if `k` exceeds the limit.
+    pub fn factorial(&self, k: usize) -> u64 {
+        assert!(k <= self.limit(), "number exceeds the table limit");
+
+        self.fact[k]
+    }
+
+    /// Returns the inverse of `k!` modulo `p`.
+    /// # Panics
+    /// Panics if `k` exceeds the limit.
+    pub fn inv_factorial(&self, k: usize) -> u64 {
+        assert!(k <= self.limit(), "number exceeds the table limit");
+
+        self.inv_fact[k]
+    }
+
+    /// Returns the binomial coefficient `C(n, k) mod p`.
+    /// Numbers beyond the limit are split into base `p` digits with Lucas' theorem.
+    /// # Panics
+    /// Panics if any required digit exceeds the limit, which is impossible if `limit == p - 1`.
+    pub fn binomial(&self, n: u64, k: u64) -> u64 {
+        if k > n {
+            return 0;
+        }
+
+        let (mut n, mut k) = (n, k);
+        let mut res = 1 % self.p;
+
+        // C(n, k) ≡ ∏ C(n_i, k_i) (mod p), where n_i and k_i are base p digits
+        while k > 0 {
+            let (ni, ki) = ((n % self.p) as usize, (k % self.p) as usize);
+            if ki > ni {
+                return 0;
+            }
+
+            res = mod_mul(res, self.small_binomial(ni, ki), self.p);
+            n /= self.p;
+            k /= self.p;
+        }
+
+        res
+    }
+
+    /// Returns the count of `k`-permutations of `n` elements `n! / (n - k)! mod p`.
+    /// # Panics
+    /// Panics if `n mod p` exceeds the limit.
+    pub fn permutations(&self, n: u64, k: u64) -> u64 {
+        if k > n {
+            return 0;
+        }
+
+        // the product n * (n - 1) * ... * (n - k + 1) has a multiple of p unless it's within a single block of p numbers
+        if n / self.p != (n - k) / self.p {
+            return 0;
+        }
+
+        mod_mul(
+            self.factorial((n % self.p) as usize),
+            self.inv_factorial(((n - k) % self.p) as usize),
+            self.p,
+        )
+    }
+
+    /// Returns the multinomial coefficient `(k`<sub>`1`</sub>` + ... + k`<sub>`m`</sub>`)! / (k`<sub>`1`</sub>`! * ... * k`<sub>`m`</sub>`!) mod p`.
+    /// # Panics
+    /// Panics if the sum overflows `u64` or a binomial coefficient can't be found (see [`Combinatorics::binomial`]).
+    pub fn multinomial(&self, ks: &[u64]) -> u64 {
+        let mut total = 0u64;
+
+        ks.iter().fold(1 % self.p, |res, &k| {
+            total = total.checked_add(k).expect("sum overflows u64");
+            mod_mul(res, self.binomial(total, k), self.p)
+        })
+    }
+
+    fn small_binomial(&self, n: usize, k: usize) -> u64 {
+        mod_mul(
+            self.factorial(n),
+            mod_mul(self.inv_fact[k], self.inv_fact[n - k], self.p),
+            self.p,
+        )
+    }
+}
+
+/// Finds the binomial coefficient `C(n, k)` modulo an arbitrary number `m`.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::binomial_mod;
+///
+/// let res0 = binomial_mod(1000, 400, 1024 * 243 * 1_000_003);
+/// let res1 = binomial_mod(1_000_000, 300_000, 1_000_000_000);
+/// let res2 = binomial_mod(100, 50, u64::MAX);
+///
+/// assert_eq!(186_638_493_006, res0);
+/// assert_eq!(134_272_000, res1);
+/// assert_eq!(100_891_344_545_564_193_334_812_497_256 % u64::MAX as u128, res2 as u128);
+/// ```
+/// ## Corner cases
+/// - Binomial coefficient equals 0 if `k > n`.
+/// - Every number is congruent to 0 modulo 1.
+/// ```
+/// use ads_rs::prelude::v1::math::binomial_mod;
+///
+/// assert_eq!(0, binomial_mod(3, 5, 7));
+/// assert_eq!(0, binomial_mod(10, 5, 1));
+/// ```
+/// # Panics
+/// - Panics if `m == 0`.
+/// - Panics if `m` has a large prime factor `p` (or a large prime power factor `p`<sup>`e`</sup>) and `k` is large too,
+///   i.e. more than 2<sup>30</sup> multiplications would be needed, see the implementation details.
+///   There's no sublinear algorithm in this case, e.g. `C(p - 1, p / 2)` modulo the largest 64-bit prime `p`.
+/// ```should_panic
+/// use ads_rs::prelude::v1::math::binomial_mod;
+///
+/// binomial_mod(18_446_744_073_709_551_556, 9_223_372_036_854_775_778, 18_446_744_073_709_551_557);
+/// ```
+/// # Implementation details
+/// - `m` is factorized (from [`factorize`]) and the results modulo prime powers are combined with [`crt`].
+/// - Modulo a prime `p` Lucas' theorem is used, every `C(n`<sub>`i`</sub>`, k`<sub>`i`</sub>`)` of base `p` digits
+///   is computed multiplicatively in `min(k`<sub>`i`</sub>`, n`<sub>`i`</sub>` - k`<sub>`i`</sub>`)` steps without tables.
+/// - Modulo a prime power `p`<sup>`e`</sup> the power of `p` in `C(n, k)` is found with Legendre's formula,
+///   the rest is a ratio of products of numbers without factors of `p`:
+///   - if `min(n, p`<sup>`e`</sup>`)` is at most 2<sup>22</sup>, Granville's generalization of Lucas' theorem is used,
+///     the factorials are products of a prefix table modulo `p`<sup>`e`</sup> over base `p` digits;
+///   - otherwise `n * (n - 1) * ... * (n - r + 1) / r!` for `r = min(k, n - k)` is computed directly,
+///     every term is stripped of factors of `p` and the denominator is inverted modulo `p`<sup>`e`</sup>.
+/// - Time complexity is O(m<sup>1/4</sup> + Σ T(p<sup>e</sup>) + log<sub>2</sub>(n)) expected, where the sum is over
+///   the prime power factors of `m`, T(p) = min(k, n - k, p * log<sub>p</sub>(n)) for primes, T(p<sup>e</sup>) = min(n, p<sup>e</sup>)
+///   if it's at most 2<sup>22</sup> and T(p<sup>e</sup>) = min(k, n - k) * log<sub>p</sub>(n) otherwise.
+pub fn binomial_mod(n: u64, k: u64, m: u64) -> u64 {
+    assert!(m != 0, "modulus equals 0");
+
+    if k > n {
+        return 0;
+    }
+
+    let congruences: Vec<(u64, u64)> = factorize(m)
+        .into_iter()
+        .map(|(p, e)| {
+            let pe = p.pow(e);
+            (binomial_mod_prime_power(n, k, p, e, pe), pe)
+        })
+        .collect();
+
+    crt(&congruences).unwrap().0
+}
+
+/// Finds the exact binomial coefficient `C(n, k)`.
+/// Returns `None` if the result doesn't fit into `u128`.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::binomial;
+///
+/// let res0 = binomial(10, 3);
+/// let res1 = binomial(128, 64);
+/// let res2 = binomial(u64::MAX, 2);
+/// let res3 = binomial(132, 66);
+///
+/// assert_eq!(Some(120), res0);
+/// assert_eq!(Some(23_951_146_041_928_082_866_135_587_776_380_551_750), res1);
+/// assert_eq!(Some(u64::MAX as u128 * (u64::MAX as u128 - 1) / 2), res2);
+/// assert_eq!(None, res3);
+/// ```
+/// ## Corner cases
+/// - Binomial coefficient equals 0 if `k > n`.
+/// - `C(n, 0) = C(n, n) = 1`.
+/// ```
+/// use ads_rs::prelude::v1::math::binomial;
+///
+/// assert_eq!(Some(0), binomial(3, 5));
+/// assert_eq!(Some(1), binomial(u64::MAX, u64::MAX));
+/// ```
+/// # Implementation details
+/// - `C(n, i + 1) = C(n, i) * (n - i) / (i + 1)`, the fraction is reduced with [`gcd`] first,
+///   so the denominator divides `C(n, i)` and the intermediate values never exceed the result.
+/// - Time complexity is O(min(k, n - k) * log<sub>2</sub><sup>2</sup>(n)), the loop stops after at most 128 steps on overflow.
+pub fn binomial(n: u64, k: u64) -> Option<u128> {
+    if k > n {
+        return Some(0);
+    }
+
+    let k = k.min(n - k);
+    let mut res = 1u128;
+
+    for i in 0..k {
+        let (numer, denom) = ((n - i) as u128, (i + 1) as u128);
+        let g = gcd(numer, denom);
+
+        res = (res / (denom / g)).checked_mul(numer / g)?;
+    }
+
+    Some(res)
+}
+
+/// The largest prefix table [`binomial_mod_prime_power`] is allowed to build.
+const PRIME_POWER_TABLE_LIMIT: u64 = 1 << 22;
+
+/// The largest number of multiplications of a direct product [`binomial_mod`] is allowed to perform.
+const DIRECT_PRODUCT_LIMIT: u64 = 1 << 30;
+
+/// Finds `C(n, k) mod p` for a prime `p` and `k <= n` with Lucas' theorem.
+fn binomial_mod_prime(n: u64, k: u64, p: u64) -> u64 {
+    // pairs of base p digits from the lowest one
+    let digits: Vec<(u64, u64)> =
+        std::iter::successors(Some((n, k)), |&(n, k)| (k >= p).then_some((n / p, k / p)))
+            .map(|(n, k)| (n % p, k % p))
+            .collect();
+
+    if digits.iter().any(|&(n_digit, k_digit)| k_digit > n_digit) {
+        return 0;
+    }
+
+    let steps = digits.iter().fold(0u64, |acc, &(n_digit, k_digit)| {
+        acc.saturating_add(k_digit.min(n_digit - k_digit))
+    });
+    assert!(
+        steps <= DIRECT_PRODUCT_LIMIT,
+        "binomial coefficient modulo a large prime is infeasible"
+    );
+
+    let (mut numer, mut denom) = (1 % p, 1 % p);
+    for (n_digit, k_digit) in digits {
+        // every factor is below p, so it's invertible
+        for i in 0..k_digit.min(n_digit - k_digit) {
+            numer = mod_mul(numer, n_digit - i, p);
+            denom = mod_mul(denom, i + 1, p);
+        }
+    }
+
+    mod_mul(numer, mod_inv(denom, p).unwrap(), p)
+}
+
+/// Finds `C(n, k) mod p`<sup>`e`</sup> for `k <= n`.
+fn binomial_mod_prime_power(n: u64, k: u64, p: u64, e: u32, pe: u64) -> u64 {
+    if e == 1 {
+        return binomial_mod_prime(n, k, p);
+    }
+
+    let v = legendre_exponent(n, p) - legendre_exponent(k, p) - legendre_exponent(n - k, p);
+    if v >= e as u64 {
+        return 0;
+    }
+
+    let unit = if n.min(pe - 1) <= PRIME_POWER_TABLE_LIMIT {
+        binomial_unit_granville(n, k, p, e, pe)
+    } else {
+        binomial_unit_direct(n, k, p, pe)
+    };
+
+    mod_mul(unit, mod_pow(p, v, pe), pe)
+}
+
+/// Finds `C(n, k) / p`<sup>`v`</sup>` mod p`<sup>`e`</sup> with a prefix table of size `min(n, p`<sup>`e`</sup>`)`,
+/// where `v` is the exponent of `p` in `C(n, k)`.
+fn binomial_unit_granville(n: u64, k: u64, p: u64, e: u32, pe: u64) -> u64 {
+    // prefix products of numbers coprime with p, every required index is below min(n, p^e)
+    let size = n.min(pe - 1) as usize + 1;
+    let mut prefix = vec![1 % pe; size];
+    for i in 1..size {
+        prefix[i] = if (i as u64).is_multiple_of(p) {
+            prefix[i - 1]
+        } else {
+            mod_mul(prefix[i - 1], i as u64, pe)
+        };
+    }
+
+    // the product of all units modulo p^e equals -1, except for p = 2 and e >= 3 (Gauss's generalization of Wilson's theorem)
+    let period_negates = p != 2 || e < 3;
+
+    // n! without factors of p, as n! = (product of numbers coprime with p up to n) * p^(n / p) * (n / p)!
+    let factorial_unit = |mut x: u64| {
+        let mut res = 1 % pe;
+
+        while x > 0 {
+            res = mod_mul(res, prefix[(x % pe) as usize], pe);
+            if period_negates && (x / pe) % 2 == 1 {
+                res = (pe - res) % pe;
+            }
+            x /= p;
+        }
+
+        res
+    };
+
+    let denom = mod_mul(factorial_unit(k), factorial_unit(n - k), pe);
+
+    mod_mul(factorial_unit(n), mod_inv(denom, pe).unwrap(), pe)
+}
+
+/// Finds `C(n, k) / p`<sup>`v`</sup>` mod p`<sup>`e`</sup> as a product of `min(k, n - k)` fractions,
+/// where `v` is the exponent of `p` in `C(n, k)`.
+fn binomial_unit_direct(n: u64, k: u64, p: u64, pe: u64) -> u64 {
+    let r = k.min(n - k);
+    assert!(
+        r <= DIRECT_PRODUCT_LIMIT,
+        "binomial coefficient modulo a large prime power is infeasible"
+    );
+
+    let strip = |mut x: u64| {
+        while x.is_multiple_of(p) {
+            x /= p;
+        }
+
+        x % pe
+    };
+
+    let (mut numer, mut denom) = (1 % pe, 1 % pe);
+    for i in 0..r {
+        numer = mod_mul(numer, strip(n - i), pe);
+        denom = mod_mul(denom, strip(i + 1), pe);
+    }
+
+    mod_mul(numer, mod_inv(denom, pe).unwrap(), pe)
+}
+
+/// Finds the exponent of a prime `p` in `n!` with Legendre's formula.
+fn legendre_exponent(mut n: u64, p: u64) -> u64 {
+    let mut res = 0;
+
+    while n > 0 {
+        n /= p;
+        res += n;
+    }
+
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pascal_triangle(n: usize) -> Vec<Vec<Option<u128>>> {
+        let mut rows: Vec<Vec<Option<u128>>> = vec![vec![Some(1)]];
+
+        for i in 1..=n {
+            let prev = &rows[i - 1];
+            let mut row = vec![Some(1); i + 1];
+            for j in 1..i {
+                row[j] = prev[j - 1].zip(prev[j]).and_then(|(a, b)| a.checked_add(b));
+            }
+            rows.push(row);
+        }
+
+        rows
+    }
+
+    fn pascal_triangle_mod(n: usize, m: u64) -> Vec<Vec<u64>> {
+        let mut rows = vec![vec![1 % m]];
+
+        for i in 1..=n {
+            let prev = &rows[i - 1];
+            let mut row = vec![1 % m; i + 1];
+            for j in 1..i {
+                row[j] = (prev[j - 1] + prev[j]) % m;
+            }
+            rows.push(row);
+        }
+
+        rows
+    }
+
+    #[test]
+    fn binomial_matches_pascal_triangle() {
+        let rows = pascal_triangle(200);
+
+        for (n, row) in rows.iter().enumerate() {
+            for k in 0..=n + 1 {
+                let expected = row.get(k).copied().unwrap_or(Some(0));
+                assert_eq!(expected, binomial(n as u64, k as u64), "n = {n}, k = {k}");
+            }
+        }
+    }
+
+    #[test]
+    fn combinatorics_matches_pascal_triangle() {
+        for p in [2, 3, 7, 13, 101] {
+            let rows = pascal_triangle_mod(300, p);
+            let full = Combinatorics::new(usize::MAX, p);
+            let partial = Combinatorics::new(50, p);
+
+            for (n, row) in rows.iter().enumerate() {
+                for (k, &expected) in row.iter().enumerate() {
+                    assert_eq!(
+                        expected,
+                        full.binomial(n as u64, k as u64),
+                        "p = {p}, n = {n}, k = {k}"
+                    );
+
+                    if n <= partial.limit() {
+                        assert_eq!(expected, partial.binomial(n as u64, k as u64));
+                    }
+
+                    let perm = (0..k as u64).fold(1, |acc, i| acc * ((n as u64 - i) % p) % p);
+                    assert_eq!(
+                        perm,
+                        full.permutations(n as u64, k as u64),
+                        "p = {p}, n = {n}, k = {k}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn multinomial_works() {
+        // arrange
+        let comb = Combinatorics::new(1000, 998_244_353);
+        let test_suits = [
+            // Empty product
+            (vec![], 1),
+            // Binomial coefficient
+            (vec![3, 7], 120),
+            // Permutations of "MISSISSIPPI"
+            (vec![1, 4, 4, 2], 34_650),
+            // Zeros don't change the coefficient
+            (vec![0, 2, 0, 2, 0], 6),
+        ];
+
+        // act
+        let result: Vec<u64> = test_suits.iter().map(|t| comb.multinomial(&t.0)).collect();
+
+        // assert
+        for i in 0..test_suits.len() {
+            assert_eq!(test_suits[i].1, result[i]);
+        }
+    }
+
+    #[test]
+    fn binomial_mod_matches_pascal_triangle() {
+        for m in 1..=200 {
+            let rows = pascal_triangle_mod(100, m);
+
+            for (n, row) in rows.iter().enumerate() {
+                for (k, &expected) in row.iter().enumerate() {
+                    assert_eq!(
+                        expected,
+                        binomial_mod(n as u64, k as u64, m),
+                        "m = {m}, n = {n}, k = {k}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn binomial_mod_matches_exact() {
+        for m in [
+            1 << 63,
+            u64::MAX,
+            3u64.pow(40),
+            18_446_744_073_709_551_557,
+            998_244_353 * 1_000_000_007,
+        ] {
+            for n in (0..=131).step_by(10) {
+                for k in 0..=n {
+                    let expected = (binomial(n, k).unwrap() % m as u128) as u64;
+                    assert_eq!(expected, binomial_mod(n, k, m), "m = {m}, n = {n}, k = {k}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn binomial_mod_large_prime_works() {
+        // arrange
+        let p = 1_000_000_007;
+        let test_suits = [
+            // n is much greater than p, C(n, 3) = n * (n - 1) * (n - 2) / 6
+            (
+                1_000_000_000_000,
+                3,
+                p,
+                falling_mod(1_000_000_000_000, 3, p),
+            ),
+            // Lucas' theorem: C(p^2 + 2p + 3, p + 1) = C(1, 0) * C(2, 1) * C(3, 1)
+            (p * p + 2 * p + 3, p + 1, p, 6),
+            // a digit of k exceeds the digit of n
+            (p * p + p - 1, p, p, 0),
+            // C(n, n) = 1
+            (u64::MAX, u64::MAX, p, 1),
+            // the largest 64-bit prime, n < p
+            (
+                1 << 63,
+                3,
+                18_446_744_073_709_551_557,
+                falling_mod(1 << 63, 3, 18_446_744_073_709_551_557),
+            ),
+            // the largest 64-bit prime, n = p + 57, so C(n, 3) = C(1, 0) * C(57, 3)
+            (u64::MAX - 1, 3, 18_446_744_073_709_551_557, 29_260),
+            // the largest 64-bit prime, n - k is small
+            (
+                u64::MAX - 1,
+                u64::MAX - 3,
+                18_446_744_073_709_551_557,
+                1_596,
+            ),
+            // the digits of n - k are small
+            (
+                u64::MAX,
+                u64::MAX - 2,
+                998_244_353,
+                falling_mod(u64::MAX, 2, 998_244_353),
+            ),
+        ];
+
+        // act
+        let result: Vec<u64> = test_suits
+            .iter()
+            .map(|&(n, k, m, _)| binomial_mod(n, k, m))
+            .collect();
+
+        // assert
+        for i in 0..test_suits.len() {
+            assert_eq!(test_suits[i].3, result[i]);
+        }
+    }
+
+    #[test]
+    fn binomial_mod_large_prime_power_works() {
+        // arrange
+        let test_suits = [
+            // power of two, n is greater than the prefix table limit
+            (10_000_000, 3, 1 << 40),
+            (10_000_000, 9_999_997, 1 << 40),
+            (1 << 30, 4, 1 << 63),
+            // odd prime powers, the factors of p in the terms cancel out
+            (1 << 40, 3, 5_000_011 * 5_000_011),
+            (2 * 3u64.pow(19), 4, 3u64.pow(40)),
+            (3u64.pow(21) + 1, 3, 3u64.pow(40)),
+        ];
+
+        // act
+        let result: Vec<u64> = test_suits
+            .iter()
+            .map(|&(n, k, m)| binomial_mod(n, k, m))
+            .collect();
+
+        // assert
+        for i in 0..test_suits.len() {
+            let (n, k, m) = test_suits[i];
+            let expected = (binomial(n, k).unwrap() % m as u128) as u64;
+            assert_eq!(expected, result[i], "n = {n}, k = {k}, m = {m}");
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "binomial coefficient modulo a large prime power is infeasible")]
+    fn binomial_mod_large_prime_power_panics() {
+        binomial_mod(1 << 62, 1 << 61, 5_000_011 * 5_000_011);
+    }
+
+    fn falling_mod(n: u64, k: u64, p: u64) -> u64 {
+        let (numer, denom) = (0..k).fold((1, 1), |(numer, denom), i| {
+            (mod_mul(numer, (n - i) % p, p), mod_mul(denom, i + 1, p))
+        });
+
+        mod_mul(numer, mod_inv(denom, p).unwrap(), p)
+    }
+}
@@ -0,0 +1,386 @@
+use crate::v1::math::{Integer, Ratio};
+use std::cmp::Ordering;
+use std::iter::FusedIterator;
+use std::mem::replace;
+
+/// Count of fractional bits of `x` taken into account by [`best_rational_approximation`].
+const FRACTION_BITS: u32 = 126;
+
+/// Finds the continued fraction expansion of `p / q`.
+/// Returns an iterator over terms `[a0; a1, a2, ...]`, where `a0 = floor(p / q)` and the rest are positive.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::continued_fraction;
+///
+/// let res0: Vec<u64> = continued_fraction(415, 93).collect();
+/// let res1: Vec<i32> = continued_fraction(-415, 93).collect();
+/// let res2: Vec<i32> = continued_fraction(415, -93).collect();
+///
+/// assert_eq!(vec![4, 2, 6, 7], res0);
+/// assert_eq!(vec![-5, 1, 1, 6, 7], res1);
+/// assert_eq!(vec![-5, 1, 1, 6, 7], res2);
+/// ```
+/// ## Corner case
+/// The expansion of an integer consists of a single term.
+/// ```
+/// use ads_rs::prelude::v1::math::continued_fraction;
+///
+/// let res0: Vec<i8> = continued_fraction(0, -5).collect();
+/// let res1: Vec<i8> = continued_fraction(i8::MIN, 1).collect();
+///
+/// assert_eq!(vec![0], res0);
+/// assert_eq!(vec![i8::MIN], res1);
+/// ```
+/// # Panics
+/// Panics if `q == 0` or `p / q` in lowest terms doesn't fit into `T` (see [`Ratio::new`]).
+/// # Implementation details
+/// - Terms are the quotients of Euclid's algorithm, the same loop as in [`extended_gcd`](crate::prelude::v1::math::extended_gcd).
+/// - Time complexity is O(log<sub>2</sub>(min(p, q)))
+pub fn continued_fraction<T: Integer>(p: T, q: T) -> ContinuedFraction<T> {
+    let ratio = Ratio::new(p, q);
+    let (first, rem) = ratio.div_rem_floor();
+
+    ContinuedFraction {
+        first: Some(first),
+        numer: ratio.denom(),
+        denom: rem,
+    }
+}
+
+/// Finds the convergents of the continued fraction expansion of `p / q`.
+/// Returns an iterator over ratios `[a0]`, `[a0; a1]`, `[a0; a1, a2]`, ..., the last one equals `p / q`.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::{convergents, Ratio};
+///
+/// let res0: Vec<Ratio<u64>> = convergents(415, 93).collect();
+/// let res1: Vec<Ratio<i64>> = convergents(-415, 93).collect();
+///
+/// assert_eq!(vec![Ratio::new(4, 1), Ratio::new(9, 2), Ratio::new(58, 13), Ratio::new(415, 93)], res0);
+/// assert_eq!(
+///     vec![Ratio::new(-5, 1), Ratio::new(-4, 1), Ratio::new(-9, 2), Ratio::new(-58, 13), Ratio::new(-415, 93)],
+///     res1
+/// );
+/// ```
+/// ## Corner case
+/// Convergents never exceed `p / q` in lowest terms, so they always fit into `T`.
+/// ```
+/// use ads_rs::prelude::v1::math::{convergents, Ratio};
+///
+/// let res = convergents(u8::MAX, 254).last();
+///
+/// assert_eq!(Some(Ratio::new(u8::MAX, 254)), res);
+/// ```
+/// # Panics
+/// Panics if `q == 0` or `p / q` in lowest terms doesn't fit into `T` (see [`Ratio::new`]).
+/// # Implementation details
+/// - Convergents follow the recurrence h<sub>n</sub> = a<sub>n</sub> * h<sub>n-1</sub> + h<sub>n-2</sub>
+///   for both numerators and denominators.
+/// - Time complexity is O(log<sub>2</sub>(min(p, q)))
+pub fn convergents<T: Integer>(p: T, q: T) -> Convergents<T> {
+    Convergents {
+        terms: continued_fraction(p, q),
+        numers: (T::ONE, T::ZERO),
+        denoms: (T::ZERO, T::ONE),
+    }
+}
+
+/// Finds the fraction closest to `x` among the ones with denominators not exceeding `max_denominator`.
+/// If two fractions are equally close, the one with the smaller denominator is returned.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::{best_rational_approximation, Ratio};
+///
+/// let res0 = best_rational_approximation(std::f64::consts::PI, 1000);
+/// let res1 = best_rational_approximation(29.97, 1001);
+/// let res2 = best_rational_approximation(48000.0 / 44100.0, 1000);
+/// let res3 = best_rational_approximation(-0.333333, 10);
+///
+/// assert_eq!(Some(Ratio::new(355, 113)), res0);
+/// assert_eq!(Some(Ratio::new(2997, 100)), res1);
+/// assert_eq!(Some(Ratio::new(160, 147)), res2);
+/// assert_eq!(Some(Ratio::new(-1, 3)), res3);
+/// ```
+/// ## Corner cases
+/// - Returns `None` if `x` isn't finite, `max_denominator == 0` or the result doesn't fit into `i64`.
+/// - `max_denominator` is capped at `i64::MAX`.
+/// ```
+/// use ads_rs::prelude::v1::math::best_rational_approximation;
+///
+/// assert_eq!(None, best_rational_approximation(f64::NAN, 1000));
+/// assert_eq!(None, best_rational_approximation(1e300, 1000));
+/// assert_eq!(None, best_rational_approximation(0.5, 0));
+/// ```
+/// # Implementation details
+/// - `x` is converted into an exact fraction with a power of two denominator,
+///   fractional bits below 2<sup>-126</sup> are dropped.
+/// - The continued fraction expansion is stopped at the last convergent which fits,
+///   then it's compared with the best semiconvergent exactly (using [`Ratio`] comparison).
+/// - Time complexity is O(log<sub>2</sub>(max_denominator))
+pub fn best_rational_approximation(x: f64, max_denominator: u64) -> Option<Ratio<i64>> {
+    if !x.is_finite() || max_denominator == 0 || x >= 2f64.powi(63) || x < -(2f64.powi(63)) {
+        return None;
+    }
+
+    // |x| = mantissa * 2^exp
+    let bits = x.abs().to_bits();
+    let (mantissa, exp) = match (bits & ((1 << 52) - 1), (bits >> 52) as i32) {
+        (mantissa, 0) => (mantissa, -1074),
+        (mantissa, exp) => (mantissa | 1 << 52, exp - 1075),
+    };
+
+    if exp >= 0 {
+        return Some(Ratio::from_integer(x as i64));
+    }
+
+    // |x| = int + numer / denom
+    let shift = exp.unsigned_abs();
+    let int = mantissa.checked_shr(shift).unwrap_or(0);
+    let frac = (mantissa - (int << shift.min(63))) as u128;
+    let (numer, denom) = if shift <= FRACTION_BITS {
+        (frac, 1 << shift)
+    } else {
+        (
+            frac.checked_shr(shift - FRACTION_BITS).unwrap_or(0),
+            1 << FRACTION_BITS,
+        )
+    };
+
+    let (p, q) = best_fraction(numer, denom, max_denominator.min(i64::MAX as u64) as u128);
+    let numer = i64::try_from(int as u128 * q + p).ok()?;
+
+    Some(Ratio::new(if x < 0.0 { -numer } else { numer }, q as i64))
+}
+
+/// Finds the best approximation of `numer / denom < 1` with a denominator not exceeding `max_denominator`.
+fn best_fraction(mut numer: u128, mut denom: u128, max_denominator: u128) -> (u128, u128) {
+    let (mut p0, mut q0, mut p1, mut q1) = (0, 1, 1, 0);
+
+    while denom != 0 {
+        let a = numer / denom;
+        let q2 = match a.checked_mul(q1).and_then(|q| q.checked_add(q0)) {
+            Some(q2) if q2 <= max_denominator => q2,
+            _ => break,
+        };
+
+        (p0, q0, p1, q1) = (p1, q1, p0 + a * p1, q2);
+        let new_denom = numer % denom;
+        numer = replace(&mut denom, new_denom);
+    }
+
+    if denom == 0 {
+        return (p1, q1);
+    }
+
+    // numer / denom is the complete quotient t, so x = (t * p1 + p0) / (t * q1 + q0),
+    // and the semiconvergent with the largest k is closer than p1 / q1 iff t < 2k + q0 / q1
+    let k = (max_denominator - q0) / q1;
+    let (a, r) = (numer / denom, numer % denom);
+    let semiconvergent_is_closer = match a.cmp(&(2 * k)) {
+        Ordering::Less => true,
+        Ordering::Greater => false,
+        Ordering::Equal => Ratio::new(r, denom) < Ratio::new(q0, q1),
+    };
+
+    if semiconvergent_is_closer {
+        (p0 + k * p1, q0 + k * q1)
+    } else {
+        (p1, q1)
+    }
+}
+
+/// Lazy iterator over the terms of a continued fraction expansion, see [`continued_fraction`].
+#[derive(Debug, Clone)]
+pub struct ContinuedFraction<T> {
+    first: Option<T>,
+    numer: T,
+    denom: T,
+}
+
+impl<T: Integer> Iterator for ContinuedFraction<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(first) = self.first.take() {
+            return Some(first);
+        }
+
+        if self.denom == T::ZERO {
+            return None;
+        }
+
+        // both numbers are non-negative after the first term
+        let q = self.numer / self.denom;
+        let new_denom = self.numer % self.denom;
+        self.numer = replace(&mut self.denom, new_denom);
+
+        Some(q)
+    }
+}
+
+impl<T: Integer> FusedIterator for ContinuedFraction<T> {}
+
+/// Lazy iterator over the convergents of a continued fraction expansion, see [`convergents`].
+#[derive(Debug, Clone)]
+pub struct Convergents<T> {
+    terms: ContinuedFraction<T>,
+    numers: (T, T),
+    denoms: (T, T),
+}
+
+impl<T: Integer> Iterator for Convergents<T> {
+    type Item = Ratio<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let a = self.terms.next()?;
+
+        let numer = a * self.numers.0 + self.numers.1;
+        let denom = a * self.denoms.0 + self.denoms.1;
+        self.numers = (numer, self.numers.0);
+        self.denoms = (denom, self.denoms.0);
+
+        Some(Ratio::new(numer, denom))
+    }
+}
+
+impl<T: Integer> FusedIterator for Convergents<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v1::math::prime::SplitMix64;
+
+    #[test]
+    fn continued_fraction_roundtrip_works() {
+        for p in -60i64..=60 {
+            for q in (-60i64..=60).filter(|&q| q != 0) {
+                let terms: Vec<i64> = continued_fraction(p, q).collect();
+                let value = terms
+                    .iter()
+                    .rev()
+                    .skip(1)
+                    .fold(Ratio::from_integer(*terms.last().unwrap()), |acc, &a| {
+                        Ratio::from_integer(a) + acc.recip()
+                    });
+
+                assert!(terms.iter().skip(1).all(|&a| a > 0));
+                assert_eq!(Ratio::new(p, q), value);
+                assert_eq!(Some(value), convergents(p, q).last());
+            }
+        }
+    }
+
+    #[test]
+    fn convergents_extreme_values_work() {
+        // arrange
+        let test_suits = [
+            // Consecutive Fibonacci numbers
+            (
+                (233u64, 144u64),
+                vec![
+                    (1, 1),
+                    (2, 1),
+                    (3, 2),
+                    (5, 3),
+                    (8, 5),
+                    (13, 8),
+                    (21, 13),
+                    (34, 21),
+                    (55, 34),
+                    (89, 55),
+                    (233, 144),
+                ],
+            ),
+            // Maximal values
+            (
+                (u64::MAX, u64::MAX - 1),
+                vec![(1, 1), (u64::MAX, u64::MAX - 1)],
+            ),
+            (
+                (u64::MAX - 1, u64::MAX),
+                vec![(0, 1), (1, 1), (u64::MAX - 1, u64::MAX)],
+            ),
+        ];
+
+        // act
+        let result: Vec<Vec<(u64, u64)>> = test_suits
+            .iter()
+            .map(|t| {
+                convergents(t.0 .0, t.0 .1)
+                    .map(|r| (r.numer(), r.denom()))
+                    .collect()
+            })
+            .collect();
+
+        // assert
+        for i in 0..test_suits.len() {
+            assert_eq!(test_suits[i].1, result[i]);
+        }
+    }
+
+    #[test]
+    fn best_rational_approximation_matches_brute_force() {
+        let mut rng = SplitMix64(42);
+
+        for _ in 0..2000 {
+            let x = (rng.next() >> 11) as f64 / (1u64 << 53) as f64 * 20.0 - 10.0;
+            let max_denominator = 1 + rng.next() % 300;
+
+            let expected = (1..=max_denominator as i64)
+                .map(|q| Ratio::new((x * q as f64).round() as i64, q))
+                .min_by(|a, b| {
+                    let dist = |r: &Ratio<i64>| (x - r.numer() as f64 / r.denom() as f64).abs();
+                    dist(a).total_cmp(&dist(b)).then(a.denom().cmp(&b.denom()))
+                });
+
+            assert_eq!(
+                expected,
+                best_rational_approximation(x, max_denominator),
+                "x = {x}"
+            );
+        }
+    }
+
+    #[test]
+    fn best_rational_approximation_works() {
+        // arrange
+        let test_suits = [
+            // Exact values
+            ((0.75, 4), Some((3, 4))),
+            ((-0.0, 1), Some((0, 1))),
+            ((-12.0, 1), Some((-12, 1))),
+            // Equally close fractions
+            ((0.25, 3), Some((1, 3))),
+            ((0.5, 1), Some((0, 1))),
+            ((1.5, 1), Some((1, 1))),
+            // Tiny and subnormal values
+            ((1e-300, 1_000_000), Some((0, 1))),
+            ((f64::MIN_POSITIVE / 4.0, 1), Some((0, 1))),
+            // Maximal denominators
+            (
+                (std::f64::consts::PI, u64::MAX),
+                Some((884279719003555, 281474976710656)),
+            ),
+            (
+                (std::f64::consts::E, 999_999_999_999_999_999),
+                Some((6121026514868073, 2251799813685248)),
+            ),
+            // Maximal integer part
+            ((2f64.powi(63) - 1024.0, 1), Some((9223372036854774784, 1))),
+            ((-(2f64.powi(63)), 1), Some((i64::MIN, 1))),
+            ((2f64.powi(63), 1), None),
+            ((f64::INFINITY, 1), None),
+        ];
+
+        // act
+        let result: Vec<Option<(i64, i64)>> = test_suits
+            .iter()
+            .map(|t| best_rational_approximation(t.0 .0, t.0 .1).map(|r| (r.numer(), r.denom())))
+            .collect();
+
+        // assert
+        for i in 0..test_suits.len() {
+            assert_eq!(test_suits[i].1, result[i]);
+        }
+    }
+}
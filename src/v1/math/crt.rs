@@ -0,0 +1,127 @@
+use crate::v1::math::{extended_gcd_wide, mod_mul, mod_sub};
+
+/// Solves a system of congruences `x ≡ r`<sub>`i`</sub>` (mod m`<sub>`i`</sub>`)` with the Chinese Remainder Theorem.
+/// Returns the pair `(r, lcm)` such that every solution of the system is `x ≡ r (mod lcm)`,
+/// where `lcm` is the LCM of all moduli and `0 <= r < lcm`.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::crt;
+///
+/// // coprime moduli
+/// let res0 = crt(&[(2, 3), (3, 5), (2, 7)]);
+/// // non-coprime moduli
+/// let res1 = crt(&[(1, 4), (3, 6)]);
+/// // inconsistent system
+/// let res2 = crt(&[(1, 4), (2, 6)]);
+///
+/// assert_eq!(Some((23, 105)), res0);
+/// assert_eq!(Some((9, 12)), res1);
+/// assert_eq!(None, res2);
+/// ```
+/// ## Corner cases
+/// - Solution of an empty system equals `(0, 1)`, i.e. any number.
+/// - Remainders aren't required to be less than their moduli.
+/// - `None` is returned when the combined modulus doesn't fit into `u64`, instead of wrapping.
+/// ```
+/// use ads_rs::prelude::v1::math::crt;
+///
+/// let res0 = crt(&[]);
+/// let res1 = crt(&[(10, 3)]);
+/// let res2 = crt(&[(0, 1 << 63), (0, 3)]);
+///
+/// assert_eq!(Some((0, 1)), res0);
+/// assert_eq!(Some((1, 3)), res1);
+/// assert_eq!(None, res2);
+/// ```
+/// # Panics
+/// Panics if any modulus equals 0.
+/// # Implementation details
+/// - Congruences are merged one by one, the pair of moduli `m`<sub>`1`</sub> and `m`<sub>`2`</sub>
+///   with `g = gcd(m`<sub>`1`</sub>`, m`<sub>`2`</sub>`)` is consistent only if `g` divides `r`<sub>`2`</sub>` - r`<sub>`1`</sub>.
+/// - Extended Euclid's algorithm used (from [`extended_gcd_wide`]).
+/// - Time complexity is O(K * log<sub>2</sub>(M)) where:
+///     - M - the biggest modulus.
+///     - K - congruences count.
+pub fn crt(congruences: &[(u64, u64)]) -> Option<(u64, u64)> {
+    congruences.iter().try_fold((0, 1), |(r1, m1), &(r2, m2)| {
+        let r2 = r2 % m2;
+        let (gcd, x, _) = extended_gcd_wide(m1, m2);
+
+        let diff = mod_sub(r2, r1, m2);
+        if !diff.is_multiple_of(gcd) {
+            return None;
+        }
+
+        let m2_gcd = m2 / gcd;
+        let lcm = m1.checked_mul(m2_gcd)?;
+
+        // x is the inverse of m1 / gcd modulo m2 / gcd
+        let x = x.rem_euclid(m2_gcd as i128) as u64;
+        let k = mod_mul((diff / gcd) % m2_gcd, x, m2_gcd);
+
+        // r1 + m1 * k < lcm, so it fits into u64
+        Some((r1 + m1 * k, lcm))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v1::math::lcm;
+
+    fn brute_force(congruences: &[(u64, u64)]) -> Option<(u64, u64)> {
+        let lcm = congruences.iter().fold(1, |acc, &(_, m)| lcm(acc, m));
+
+        (0..lcm)
+            .find(|x| congruences.iter().all(|&(r, m)| x % m == r % m))
+            .map(|x| (x, lcm))
+    }
+
+    #[test]
+    fn crt_matches_brute_force() {
+        for m1 in 1..=12 {
+            for m2 in 1..=12 {
+                for m3 in [1, 5, 8, 9] {
+                    for r1 in 0..m1 {
+                        for r2 in 0..m2 {
+                            let congruences = [(r1, m1), (r2, m2), (r1 + r2, m3)];
+
+                            assert_eq!(brute_force(&congruences), crt(&congruences));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn crt_wide_works() {
+        // arrange
+        let test_suits = [
+            // Large coprime moduli
+            (
+                vec![(1, 4_294_967_291), (2, 4_294_967_279)],
+                Some((1_537_228_665_292_936_541, 18_446_743_979_220_271_189)),
+            ),
+            // Large shared factor
+            (vec![(5, 1 << 62), (5, 3 << 61)], Some((5, 3 << 62))),
+            // Large shared factor, inconsistent
+            (vec![(5, 1 << 62), (6, 3 << 61)], None),
+            // Overflow of the combined modulus
+            (vec![(0, 1 << 62), (0, 5)], None),
+            // Maximal modulus
+            (
+                vec![(u64::MAX - 1, u64::MAX)],
+                Some((u64::MAX - 1, u64::MAX)),
+            ),
+        ];
+
+        // act
+        let result: Vec<Option<(u64, u64)>> = test_suits.iter().map(|t| crt(&t.0)).collect();
+
+        // assert
+        for i in 0..test_suits.len() {
+            assert_eq!(test_suits[i].1, result[i]);
+        }
+    }
+}
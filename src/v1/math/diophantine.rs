@@ -0,0 +1,305 @@
+use crate::v1::math::extended_gcd_signed;
+use std::ops::RangeInclusive;
+
+/// The general solution of a linear Diophantine equation `a * x + b * y = c`.
+///
+/// Every solution is `(x + k * step_x, y + k * step_y)` for an integer `k`.
+/// The particular solution is normalized so that `0 <= x < |step_x|` (when `step_x != 0`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LinearDiophantine {
+    /// `x` of the particular solution.
+    pub x: i128,
+    /// `y` of the particular solution.
+    pub y: i128,
+    /// Step of `x` between consecutive solutions, equals `b / gcd(a, b)`.
+    pub step_x: i128,
+    /// Step of `y` between consecutive solutions, equals `-a / gcd(a, b)`.
+    pub step_y: i128,
+}
+
+impl LinearDiophantine {
+    /// Returns the `k`-th solution of the family, the particular one for `k == 0`.
+    /// # Examples
+    /// ```
+    /// use ads_rs::prelude::v1::math::linear_diophantine;
+    ///
+    /// let solution = linear_diophantine(6, 4, 10).unwrap();
+    ///
+    /// assert_eq!((1, 1), solution.nth(0));
+    /// assert_eq!((3, -2), solution.nth(1));
+    /// assert_eq!((-1, 4), solution.nth(-1));
+    /// ```
+    pub fn nth(&self, k: i128) -> (i128, i128) {
+        (self.x + k * self.step_x, self.y + k * self.step_y)
+    }
+
+    /// Counts solutions with `x` and `y` within the given inclusive bounds.
+    /// # Examples
+    /// ```
+    /// use ads_rs::prelude::v1::math::linear_diophantine;
+    ///
+    /// let solution = linear_diophantine(6, 4, 10).unwrap();
+    ///
+    /// assert_eq!(3, solution.count_in(-1..=3, -2..=4));
+    /// assert_eq!(0, solution.count_in(-1..=3, 5..=10));
+    /// ```
+    pub fn count_in(&self, x: RangeInclusive<i64>, y: RangeInclusive<i64>) -> u128 {
+        self.k_range(x, y)
+            .map_or(0, |(lo, hi)| (hi - lo) as u128 + 1)
+    }
+
+    /// Enumerates solutions with `x` and `y` within the given inclusive bounds, ordered by `k`.
+    /// # Examples
+    /// ```
+    /// use ads_rs::prelude::v1::math::linear_diophantine;
+    ///
+    /// let solution = linear_diophantine(6, 4, 10).unwrap();
+    /// let res: Vec<(i64, i64)> = solution.solutions_in(-1..=3, -2..=4).collect();
+    ///
+    /// assert_eq!(vec![(-1, 4), (1, 1), (3, -2)], res);
+    /// ```
+    pub fn solutions_in(
+        &self,
+        x: RangeInclusive<i64>,
+        y: RangeInclusive<i64>,
+    ) -> impl Iterator<Item = (i64, i64)> {
+        let solution = *self;
+        let (lo, hi) = self.k_range(x, y).unwrap_or((1, 0));
+
+        (lo..=hi).map(move |k| {
+            let (x, y) = solution.nth(k);
+            // both lie within i64 bounds
+            (x as i64, y as i64)
+        })
+    }
+
+    /// Finds the non-empty range of `k` whose solutions satisfy both bounds.
+    fn k_range(&self, x: RangeInclusive<i64>, y: RangeInclusive<i64>) -> Option<(i128, i128)> {
+        let (x_lo, x_hi) = k_bounds(self.x, self.step_x, x)?;
+        let (y_lo, y_hi) = k_bounds(self.y, self.step_y, y)?;
+        let (lo, hi) = (x_lo.max(y_lo), x_hi.min(y_hi));
+
+        if lo > hi {
+            None
+        } else {
+            Some((lo, hi))
+        }
+    }
+}
+
+/// Finds bounds of `k` such that `value + k * step` lies within `range`, unbounded if `step == 0`.
+fn k_bounds(value: i128, step: i128, range: RangeInclusive<i64>) -> Option<(i128, i128)> {
+    let (lo, hi) = (*range.start() as i128 - value, *range.end() as i128 - value);
+
+    if lo > hi {
+        return None;
+    }
+
+    if step == 0 {
+        return if lo <= 0 && 0 <= hi {
+            Some((i128::MIN, i128::MAX))
+        } else {
+            None
+        };
+    }
+
+    let (lo, hi, step) = if step < 0 {
+        (-hi, -lo, -step)
+    } else {
+        (lo, hi, step)
+    };
+
+    // ceil(lo / step) and floor(hi / step)
+    let (lo, hi) = (-(-lo).div_euclid(step), hi.div_euclid(step));
+
+    if lo > hi {
+        None
+    } else {
+        Some((lo, hi))
+    }
+}
+
+/// Solves a linear Diophantine equation `a * x + b * y = c` in integers.
+/// Returns `None` if there is no solution, otherwise the particular solution and the step
+/// vector of the general family (see [`LinearDiophantine`]).
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::linear_diophantine;
+///
+/// let res0 = linear_diophantine(6, 4, 10).unwrap();
+/// let res1 = linear_diophantine(-7, 3, 5).unwrap();
+/// let res2 = linear_diophantine(6, 4, 7);
+///
+/// assert_eq!((1, 1, 2, -3), (res0.x, res0.y, res0.step_x, res0.step_y));
+/// assert_eq!((1, 4, 3, 7), (res1.x, res1.y, res1.step_x, res1.step_y));
+/// assert_eq!(None, res2);
+/// ```
+/// ## Corner cases
+/// - If `a` equals 0, `x` is free: `step_x == ±1` and `step_y == 0` (and symmetrically for `b`).
+/// - If both `a` and `b` equal 0 and `c != 0`, there is no solution.
+/// ```
+/// use ads_rs::prelude::v1::math::linear_diophantine;
+///
+/// let res0 = linear_diophantine(0, 5, 10).unwrap();
+/// let res1 = linear_diophantine(0, 0, 3);
+///
+/// assert_eq!((0, 2, 1, 0), (res0.x, res0.y, res0.step_x, res0.step_y));
+/// assert_eq!(None, res1);
+/// ```
+/// # Panics
+/// Panics if `a`, `b` and `c` all equal 0: every pair `(x, y)` is a solution,
+/// which isn't a one-parameter family and can't be described by [`LinearDiophantine`].
+/// ```should_panic
+/// use ads_rs::prelude::v1::math::linear_diophantine;
+///
+/// linear_diophantine(0, 0, 0);
+/// ```
+/// # Implementation details
+/// - Extended Euclid's algorithm used (from [`extended_gcd_signed`]), then the Bezout
+///   coefficients are scaled by `c / gcd(a, b)` and `x` is reduced modulo `|step_x|`.
+/// - Values are kept in `i128`, so no intermediate computation overflows.
+/// - Time complexity is O(log<sub>2</sub>(min(|a|, |b|)))
+pub fn linear_diophantine(a: i64, b: i64, c: i64) -> Option<LinearDiophantine> {
+    let (gcd, x, _) = extended_gcd_signed(a, b);
+
+    if gcd == 0 {
+        assert!(c != 0, "every pair is a solution of 0 * x + 0 * y = 0");
+        return None;
+    }
+
+    let (gcd, a, b, c) = (gcd as i128, a as i128, b as i128, c as i128);
+
+    if c % gcd != 0 {
+        return None;
+    }
+
+    let (step_x, step_y) = (b / gcd, -a / gcd);
+
+    if step_x == 0 {
+        // b == 0, so a * x = c and y is free
+        return Some(LinearDiophantine {
+            x: c / a,
+            y: 0,
+            step_x,
+            step_y,
+        });
+    }
+
+    let x = (x * (c / gcd)).rem_euclid(step_x.abs());
+    let y = (c - a * x) / b;
+
+    Some(LinearDiophantine {
+        x,
+        y,
+        step_x,
+        step_y,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn linear_diophantine_matches_brute_force() {
+        for a in -8..=8i64 {
+            for b in -8..=8i64 {
+                for c in -20..=20i64 {
+                    if a == 0 && b == 0 && c == 0 {
+                        continue;
+                    }
+
+                    let solution = linear_diophantine(a, b, c);
+                    let expected: Vec<(i64, i64)> = (-10..=10)
+                        .flat_map(|x| (-12..=12).map(move |y| (x, y)))
+                        .filter(|(x, y)| a * x + b * y == c)
+                        .collect();
+
+                    if a == 0 && b == 0 {
+                        assert_eq!(None, solution);
+                        continue;
+                    }
+
+                    match solution {
+                        None => assert!(expected.is_empty()),
+                        Some(solution) => {
+                            let mut actual: Vec<(i64, i64)> =
+                                solution.solutions_in(-10..=10, -12..=12).collect();
+                            actual.sort();
+
+                            assert_eq!(expected, actual);
+                            assert_eq!(
+                                expected.len() as u128,
+                                solution.count_in(-10..=10, -12..=12)
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn linear_diophantine_wide_works() {
+        // arrange
+        let test_suits = [
+            (i64::MAX, i64::MIN, 1),
+            (i64::MIN, i64::MIN, i64::MIN),
+            (i64::MAX, i64::MAX - 1, i64::MIN),
+            (-3, i64::MAX, i64::MAX),
+        ];
+
+        // act
+        let result: Vec<Option<LinearDiophantine>> = test_suits
+            .iter()
+            .map(|t| linear_diophantine(t.0, t.1, t.2))
+            .collect();
+
+        // assert
+        for i in 0..test_suits.len() {
+            let (a, b, c) = test_suits[i];
+            let solution = result[i].unwrap();
+            for k in -1..=1 {
+                let (x, y) = solution.nth(k);
+                assert_eq!(c as i128, a as i128 * x + b as i128 * y);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "every pair is a solution")]
+    fn linear_diophantine_zeros_panics() {
+        linear_diophantine(0, 0, 0);
+    }
+
+    #[test]
+    fn count_in_works() {
+        // arrange
+        let solution = linear_diophantine(1, -1, 0).unwrap();
+        let test_suits = [
+            // Full domain
+            (
+                (i64::MIN, i64::MAX),
+                (i64::MIN, i64::MAX),
+                u64::MAX as u128 + 1,
+            ),
+            // Empty range
+            ((1, 0), (i64::MIN, i64::MAX), 0),
+            // Disjoint ranges
+            ((0, 10), (11, 20), 0),
+            // Single point
+            ((5, 10), (-5, 5), 1),
+        ];
+
+        // act
+        let result: Vec<u128> = test_suits
+            .iter()
+            .map(|t| solution.count_in(t.0 .0..=t.0 .1, t.1 .0..=t.1 .1))
+            .collect();
+
+        // assert
+        for i in 0..test_suits.len() {
+            assert_eq!(test_suits[i].2, result[i]);
+        }
+    }
+}
@@ -0,0 +1,206 @@
+use crate::v1::collection::{Map, SortedVecMap};
+use crate::v1::math::primitive_root::order_factors;
+use crate::v1::math::{crt, gcd, isqrt, mod_inv, mod_mul, mod_pow};
+
+/// Upper bound of the baby-step table size, larger groups take more giant steps instead.
+const BABY_STEPS_LIMIT: u64 = 1 << 20;
+
+/// Finds the discrete logarithm: the smallest `x >= 0` such that `g`<sup>`x`</sup>` ≡ h (mod m)`.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::discrete_log;
+///
+/// // prime modulus
+/// let res0 = discrete_log(3, 13, 17);
+/// // non-prime modulus, g isn't coprime with it
+/// let res1 = discrete_log(6, 36, 90);
+/// // no solution
+/// let res2 = discrete_log(2, 3, 7);
+/// // smooth group order, 998244353 - 1 = 2^23 * 7 * 17
+/// let res3 = discrete_log(3, 5, 998_244_353);
+///
+/// assert_eq!(Some(4), res0);
+/// assert_eq!(Some(2), res1);
+/// assert_eq!(None, res2);
+/// assert_eq!(Some(109_353_319), res3);
+/// ```
+/// ## Corner cases
+/// - `g`<sup>0</sup> equals 1, so `x = 0` is the answer for `h ≡ 1`, even if `g ≡ 0`.
+/// - Modulo 1 every number is congruent to 0, so the answer is always 0.
+/// ```
+/// use ads_rs::prelude::v1::math::discrete_log;
+///
+/// assert_eq!(Some(0), discrete_log(0, 1, 7));
+/// assert_eq!(Some(1), discrete_log(0, 0, 7));
+/// assert_eq!(Some(0), discrete_log(5, 3, 1));
+/// ```
+/// # Panics
+/// Panics if `m == 0`.
+/// # Implementation details
+/// - While `d = gcd(g, m) > 1`, the congruence is divided by `d`, so the rest is solved for `g` coprime with `m`.
+/// - The order of `g` is found the same way as in [`multiplicative_order`](crate::prelude::v1::math::multiplicative_order).
+/// - Pohlig–Hellman algorithm reduces the problem to subgroups of prime order,
+///   which are solved with baby-step giant-step, the results are combined with [`crt`].
+/// - The baby-step table is a [`SortedVecMap`] of at most 2<sup>20</sup> entries.
+/// - Time complexity is O(Σ e * √p) over prime powers p<sup>e</sup> dividing the order of `g`,
+///   multiplied by √p / 2<sup>20</sup> for primes above 2<sup>40</sup>.
+pub fn discrete_log(g: u64, h: u64, m: u64) -> Option<u64> {
+    assert!(m != 0, "modulus equals 0");
+
+    let (mut g, mut h, mut m) = (g % m, h % m, m);
+    // g^x = coef * g^(x - shift) (mod m) after the reduction
+    let (mut coef, mut shift) = (1 % m, 0);
+
+    loop {
+        if h == coef {
+            return Some(shift);
+        }
+
+        let d = gcd(g, m);
+        if d == 1 {
+            break;
+        }
+
+        if !h.is_multiple_of(d) {
+            return None;
+        }
+
+        (h, m) = (h / d, m / d);
+        coef = mod_mul(coef, g / d, m);
+        shift += 1;
+    }
+
+    g %= m;
+
+    let target = mod_mul(h, mod_inv(coef, m)?, m);
+
+    discrete_log_coprime(g, target, m).map(|x| x + shift)
+}
+
+/// Solves `g^x = h (mod m)` for `g` coprime with `m`.
+fn discrete_log_coprime(g: u64, h: u64, m: u64) -> Option<u64> {
+    let order = order_factors(g, m);
+    let n: u64 = order.iter().map(|&(p, e)| p.pow(e)).product();
+
+    let mut congruences = vec![];
+    for &(p, e) in &order {
+        let pe = p.pow(e);
+        let (g_i, h_i) = (mod_pow(g, n / pe, m), mod_pow(h, n / pe, m));
+        // generator of the subgroup of order p
+        let gamma = mod_pow(g_i, pe / p, m);
+        let g_i_inv = mod_inv(g_i, m)?;
+
+        // x_i = d_0 + d_1 * p + ... + d_(e-1) * p^(e-1)
+        let (mut x_i, mut p_j) = (0, 1);
+        for _ in 0..e {
+            let rest = mod_mul(mod_pow(g_i_inv, x_i, m), h_i, m);
+            let digit = baby_step_giant_step(gamma, mod_pow(rest, pe / p_j / p, m), p, m)?;
+
+            x_i += digit * p_j;
+            p_j *= p;
+        }
+
+        congruences.push((x_i, pe));
+    }
+
+    let (x, _) = crt(&congruences)?;
+
+    (mod_pow(g, x, m) == h % m).then_some(x)
+}
+
+/// Finds `x` in `[0, order)` such that `g^x = h (mod m)`, where `order` is the order of `g`.
+fn baby_step_giant_step(g: u64, h: u64, order: u64, m: u64) -> Option<u64> {
+    let steps = isqrt(order).saturating_add(1).min(BABY_STEPS_LIMIT);
+
+    let mut power = 1 % m;
+    let table: SortedVecMap<u64, u64> = (0..steps)
+        .map(|j| {
+            let entry = (power, j);
+            power = mod_mul(power, g, m);
+            entry
+        })
+        .collect();
+
+    // power equals g^steps now
+    let giant = mod_inv(power, m)?;
+    let mut current = h % m;
+    for i in 0..order.div_ceil(steps) {
+        if let Some(j) = table.get(&current) {
+            return Some(i * steps + j);
+        }
+
+        current = mod_mul(current, giant, m);
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn exhaustive_search(g: u64, m: u64) -> Vec<Option<u64>> {
+        let mut logs = vec![None; m as usize];
+        let mut power = 1 % m;
+
+        // powers become periodic after at most m steps
+        for x in 0..=m {
+            logs[power as usize].get_or_insert(x);
+            power = power * g % m;
+        }
+
+        logs
+    }
+
+    #[test]
+    fn discrete_log_matches_exhaustive_search() {
+        for m in 1..=150 {
+            for g in 0..m {
+                let expected = exhaustive_search(g, m);
+
+                for h in 0..m {
+                    assert_eq!(
+                        expected[h as usize],
+                        discrete_log(g, h, m),
+                        "g = {g}, h = {h}, m = {m}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn discrete_log_works() {
+        // arrange
+        let test_suits = [
+            // Large prime with a smooth group order
+            (
+                (3, mod_pow(3, 123_456_789, 998_244_353), 998_244_353),
+                Some(123_456_789),
+            ),
+            // Safe prime, the group order has a large prime factor
+            (
+                (5, mod_pow(5, 1_000_000, 2_147_483_783), 2_147_483_783),
+                Some(1_000_000),
+            ),
+            // Power of two modulus
+            ((3, mod_pow(3, 12_345, 1 << 40), 1 << 40), Some(12_345)),
+            // h isn't a power of g
+            ((4, 5, 1_000_000_007), None),
+            // Non-coprime g with a long reduction
+            ((2, 0, 1 << 63), Some(63)),
+            ((6, 1 << 10, 1 << 20), None),
+        ];
+
+        // act
+        let result: Vec<Option<u64>> = test_suits
+            .iter()
+            .map(|t| discrete_log(t.0 .0, t.0 .1, t.0 .2))
+            .collect();
+
+        // assert
+        for i in 0..test_suits.len() {
+            assert_eq!(test_suits[i].1, result[i]);
+        }
+    }
+}
@@ -0,0 +1,343 @@
+use crate::v1::math::gcd::extended_euclid;
+use crate::v1::math::{BigInt, BigUint};
+use std::mem::replace;
+use std::ops::{Mul, Sub};
+
+/// An abstraction over rings with a division with remainder, where Euclid's algorithm terminates.
+///
+/// The trait is implemented for the signed primitive integers, [`BigInt`],
+/// [`GaussianInt`](crate::v1::math::GaussianInt) and polynomials over a prime field
+/// [`Polynomial`](crate::v1::math::poly::Polynomial). Implementing it for another type is enough to use
+/// [`euclidean_gcd`], [`euclidean_extended_gcd`] and [`euclidean_lcm`] with it.
+///
+/// These functions are separate from [`gcd`](crate::v1::math::gcd), [`extended_gcd`](crate::v1::math::extended_gcd)
+/// and [`lcm`](crate::v1::math::lcm) on purpose. The former two are generic over [`Integer`](crate::v1::math::Integer),
+/// work with unsigned numbers, use Stein's algorithm and return cofactors of type `T::Signed`, [`lcm`](crate::v1::math::lcm)
+/// works with `u64`, while a cofactor of an Euclidean domain has the type of the domain itself.
+/// A single function can't be bounded by either of two traits, so merging them would break the existing callers.
+/// Both extended versions share the same Euclid's loop.
+///
+/// Unsigned primitive integers don't implement the trait: Bezout cofactors are negative in general,
+/// so [`euclidean_extended_gcd`] can't be expressed with unsigned elements, use [`extended_gcd`](crate::v1::math::extended_gcd) instead.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::{euclidean_gcd, EuclideanDomain, GaussianInt};
+///
+/// fn is_coprime<T: EuclideanDomain>(lhs: &T, rhs: &T) -> bool {
+///     euclidean_gcd(lhs, rhs) == T::one()
+/// }
+///
+/// assert!(is_coprime(&9i64, &-28));
+/// assert!(!is_coprime(&GaussianInt::new(2, 0), &GaussianInt::new(1, 1)));
+/// assert_eq!(5, EuclideanDomain::norm(&GaussianInt::new(1, -2)));
+/// assert_eq!(-1, EuclideanDomain::canonical_unit(&-7i32));
+/// ```
+/// # Implementation details
+/// - `div_rem(a, b)` must return `(q, r)` such that `a = q * b + r` and either `r = 0` or `norm(r) < norm(b)`.
+/// - A GCD is unique up to a unit factor, [`EuclideanDomain::canonical_unit`] picks the one returned.
+pub trait EuclideanDomain: Clone + Eq + Sub<Output = Self> + Mul<Output = Self> {
+    /// The type of the Euclidean function values.
+    type Norm: Ord;
+
+    /// Returns the additive identity.
+    fn zero() -> Self;
+
+    /// Returns the multiplicative identity.
+    fn one() -> Self;
+
+    /// Checks whether the element equals zero.
+    fn is_zero(&self) -> bool {
+        *self == Self::zero()
+    }
+
+    /// Finds a quotient `q` and a remainder `r` such that `self = q * rhs + r`
+    /// and either `r = 0` or `norm(r) < norm(rhs)`.
+    /// # Panics
+    /// Implementations may panic if `rhs` equals zero.
+    fn div_rem(&self, rhs: &Self) -> (Self, Self);
+
+    /// Returns the value of the Euclidean function.
+    fn norm(&self) -> Self::Norm;
+
+    /// Returns a unit `u` such that `u * self` is the canonical element among the associates of `self`,
+    /// e.g. a non-negative integer. The unit of zero must be [`EuclideanDomain::one`].
+    fn canonical_unit(&self) -> Self;
+}
+
+macro_rules! impl_euclidean_domain {
+    ($($t:ty),+) => {$(
+        impl EuclideanDomain for $t {
+            type Norm = <$t as crate::v1::math::Integer>::Unsigned;
+
+            #[inline]
+            fn zero() -> Self {
+                0
+            }
+
+            #[inline]
+            fn one() -> Self {
+                1
+            }
+
+            #[inline]
+            fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+                // MIN / -1 overflows, but the wrapped quotient still satisfies MIN = q * -1 + 0
+                if *rhs == -1 {
+                    return (self.wrapping_neg(), 0);
+                }
+
+                (self / rhs, self % rhs)
+            }
+
+            #[inline]
+            fn norm(&self) -> Self::Norm {
+                self.unsigned_abs()
+            }
+
+            #[inline]
+            fn canonical_unit(&self) -> Self {
+                if *self < 0 {
+                    -1
+                } else {
+                    1
+                }
+            }
+        }
+    )+};
+}
+
+impl_euclidean_domain!(i8, i16, i32, i64, i128, isize);
+
+impl EuclideanDomain for BigInt {
+    type Norm = BigUint;
+
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn one() -> Self {
+        Self::from(1)
+    }
+
+    fn is_zero(&self) -> bool {
+        BigInt::is_zero(self)
+    }
+
+    fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        BigInt::div_rem(self, rhs)
+    }
+
+    fn norm(&self) -> Self::Norm {
+        self.magnitude().clone()
+    }
+
+    fn canonical_unit(&self) -> Self {
+        Self::from(if self.is_negative() { -1 } else { 1 })
+    }
+}
+
+/// Finds a GCD (Greatest Common Divisor) of two elements of an Euclidean domain.
+/// The result is normalized with [`EuclideanDomain::canonical_unit`].
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::{euclidean_gcd, BigInt, GaussianInt};
+///
+/// let res0 = euclidean_gcd(&-42i64, &144);
+/// let res1 = euclidean_gcd(&(BigInt::from(1) << 100), &BigInt::from(-6).pow(30));
+/// let res2 = euclidean_gcd(&GaussianInt::new(5, 0), &GaussianInt::new(3, 1));
+///
+/// assert_eq!(6, res0);
+/// assert_eq!(BigInt::from(1) << 30, res1);
+/// assert_eq!(GaussianInt::new(1, 2), res2);
+/// ```
+/// ## Corner case
+/// GCD of both zero elements equals zero.
+/// ```
+/// use ads_rs::prelude::v1::math::euclidean_gcd;
+///
+/// let res = euclidean_gcd(&0i32, &0);
+///
+/// assert_eq!(0, res);
+/// ```
+/// # Panics
+/// For the primitive integers the GCD of `T::MIN` and `0` (or `T::MIN`) equals 2<sup>`T::BITS - 1`</sup>,
+/// which doesn't fit into `T`, so its normalization overflows. Every other pair is supported.
+/// # Implementation details
+/// - Euclid's algorithm used.
+/// - Time complexity is O(log(min(norm(lhs), norm(rhs)))) divisions for the integers and the Gaussian integers.
+pub fn euclidean_gcd<T: EuclideanDomain>(lhs: &T, rhs: &T) -> T {
+    let (mut lhs, mut rhs) = (lhs.clone(), rhs.clone());
+
+    while !rhs.is_zero() {
+        let (_, rem) = lhs.div_rem(&rhs);
+        lhs = replace(&mut rhs, rem);
+    }
+
+    lhs.canonical_unit() * lhs
+}
+
+/// Finds an extended GCD (Greatest Common Divisor) of two elements of an Euclidean domain.
+/// "Extended" means that algorithm will return not only GCD, but coefficients `x` and `y` such that
+///
+/// x * lhs + y * rhs = gcd(lhs, rhs)
+///
+/// The GCD is normalized with [`EuclideanDomain::canonical_unit`], the coefficients are multiplied by the same unit.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::{euclidean_extended_gcd, GaussianInt};
+///
+/// let res0 = euclidean_extended_gcd(&161i64, &28);
+/// let res1 = euclidean_extended_gcd(&GaussianInt::new(5, 0), &GaussianInt::new(3, 1));
+///
+/// assert_eq!((7, -1, 6), res0);
+/// assert_eq!((GaussianInt::new(1, 2), GaussianInt::new(-1, 0), GaussianInt::new(2, 0)), res1);
+/// ```
+/// ## Corner cases
+/// Zeros are handled like in [`extended_gcd`](crate::v1::math::extended_gcd):
+/// - Result of `euclidean_extended_gcd(0, 0)` equals tuple `(0, 1, 0)`.
+/// - Result of `euclidean_extended_gcd(0, b)` equals tuple `(b, 0, 1)` for a canonical `b`.
+/// ```
+/// use ads_rs::prelude::v1::math::euclidean_extended_gcd;
+///
+/// let res0 = euclidean_extended_gcd(&0i64, &0);
+/// let res1 = euclidean_extended_gcd(&0i64, &5);
+/// let res2 = euclidean_extended_gcd(&0i64, &-5);
+///
+/// assert_eq!((0, 1, 0), res0);
+/// assert_eq!((5, 0, 1), res1);
+/// assert_eq!((5, 0, -1), res2);
+/// ```
+/// # Panics
+/// For the primitive integers the GCD of `T::MIN` and `0` (or `T::MIN`) equals 2<sup>`T::BITS - 1`</sup>,
+/// which doesn't fit into `T`, so its normalization overflows. Every other pair is supported.
+/// # Implementation details
+/// - Euclid's algorithm used, the loop is shared with [`extended_gcd`](crate::v1::math::extended_gcd).
+/// - Time complexity is O(log(min(norm(lhs), norm(rhs)))) divisions for the integers and the Gaussian integers.
+pub fn euclidean_extended_gcd<T: EuclideanDomain>(lhs: &T, rhs: &T) -> (T, T, T) {
+    let (gcd, x, y) = extended_euclid(
+        lhs.clone(),
+        rhs.clone(),
+        [T::one(), T::zero()],
+        T::is_zero,
+        T::div_rem,
+    );
+    let unit = gcd.canonical_unit();
+
+    (unit.clone() * gcd, unit.clone() * x, unit * y)
+}
+
+/// Finds an LCM (Least Common Multiple) of two elements of an Euclidean domain.
+/// The result is normalized with [`EuclideanDomain::canonical_unit`].
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::{euclidean_lcm, GaussianInt};
+///
+/// let res0 = euclidean_lcm(&-4i64, &6);
+/// let res1 = euclidean_lcm(&GaussianInt::new(1, 1), &GaussianInt::new(2, 0));
+///
+/// assert_eq!(12, res0);
+/// assert_eq!(GaussianInt::new(2, 0), res1);
+/// ```
+/// ## Corner case
+/// LCM with zero equals zero.
+/// ```
+/// use ads_rs::prelude::v1::math::euclidean_lcm;
+///
+/// let res = euclidean_lcm(&0i64, &5);
+///
+/// assert_eq!(0, res);
+/// ```
+/// # Panics
+/// For the primitive integers the normalization overflows if the LCM doesn't fit into `T`.
+/// # Implementation details
+/// - The LCM equals `lhs / gcd(lhs, rhs) * rhs` (GCD from [`euclidean_gcd`]).
+/// - Time complexity is the same as of [`euclidean_gcd`].
+pub fn euclidean_lcm<T: EuclideanDomain>(lhs: &T, rhs: &T) -> T {
+    if lhs.is_zero() || rhs.is_zero() {
+        return T::zero();
+    }
+
+    let (quot, _) = lhs.div_rem(&euclidean_gcd(lhs, rhs));
+    let lcm = quot * rhs.clone();
+
+    lcm.canonical_unit() * lcm
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v1::math::prime::SplitMix64;
+    use crate::v1::math::{gcd, lcm};
+
+    #[test]
+    fn matches_integer_gcd() {
+        let mut rng = SplitMix64(42);
+
+        for _ in 0..1000 {
+            let a = rng.next() as i32 as i64;
+            let b = (rng.next() as i32 >> (rng.next() % 32)) as i64;
+            let (g, x, y) = euclidean_extended_gcd(&a, &b);
+
+            assert_eq!(gcd(a, b), g, "a = {a}, b = {b}");
+            assert_eq!(g, euclidean_gcd(&a, &b));
+            assert_eq!(g, x * a + y * b);
+            assert_eq!(
+                lcm(a.unsigned_abs(), b.unsigned_abs()) as i64,
+                euclidean_lcm(&a, &b)
+            );
+        }
+    }
+
+    #[test]
+    fn min_values_work() {
+        // arrange
+        let test_suits = [
+            // MIN / -1 overflows
+            (i64::MIN, -1, 1),
+            (-1, i64::MIN, 1),
+            (i64::MIN, 1, 1),
+            (i64::MIN, 2, 2),
+            (i64::MIN, -6, 2),
+            (i64::MIN, i64::MIN + 1, 1),
+            (i64::MAX, i64::MIN, 1),
+            (i64::MIN, 1 << 62, 1 << 62),
+        ];
+
+        // act
+        let result: Vec<(i64, i64, i64)> = test_suits
+            .iter()
+            .map(|t| euclidean_extended_gcd(&t.0, &t.1))
+            .collect();
+
+        // assert
+        for i in 0..test_suits.len() {
+            let (a, b, expected) = test_suits[i];
+            let (g, x, y) = result[i];
+            assert_eq!(expected, g);
+            assert_eq!(expected, euclidean_gcd(&a, &b));
+            assert_eq!(
+                expected as i128,
+                x as i128 * a as i128 + y as i128 * b as i128
+            );
+        }
+    }
+
+    #[test]
+    fn big_int_works() {
+        let mut rng = SplitMix64(42);
+
+        for _ in 0..100 {
+            let c = BigInt::from(rng.next() as i64);
+            let a = &c * BigInt::from(rng.next() as i64 as i128 * rng.next() as i128);
+            let b = &c * BigInt::from(rng.next() as i64);
+            let (g, x, y) = euclidean_extended_gcd(&a, &b);
+
+            assert_eq!(BigInt::from(a.gcd(&b)), g);
+            assert_eq!(g, x * &a + y * &b);
+            assert_eq!(
+                BigInt::from(a.magnitude().lcm(b.magnitude())),
+                euclidean_lcm(&a, &b)
+            );
+        }
+    }
+}
@@ -0,0 +1,235 @@
+use crate::v1::math::prime::SplitMix64;
+use crate::v1::math::{gcd, is_prime, mod_add, mod_mul};
+
+/// Upper bound (exclusive) of divisors checked by trial division.
+const TRIAL_DIVISION_BOUND: u64 = 1 << 10;
+
+/// Count of the polynomial iterations between GCD calls in Pollard–Brent rho.
+const BATCH_SIZE: u64 = 128;
+
+/// Finds the prime factorization of a number.
+/// Returns pairs `(prime, exponent)` sorted by primes.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::factorize;
+///
+/// let res0 = factorize(360);
+/// let res1 = factorize(18446744073709551557);
+/// let res2 = factorize(4294967291 * 4294967279);
+///
+/// assert_eq!(vec![(2, 3), (3, 2), (5, 1)], res0);
+/// assert_eq!(vec![(18446744073709551557, 1)], res1);
+/// assert_eq!(vec![(4294967279, 1), (4294967291, 1)], res2);
+/// ```
+/// ## Corner cases
+/// Factorizations of 0 and 1 are empty.
+/// ```
+/// use ads_rs::prelude::v1::math::factorize;
+///
+/// let res0 = factorize(0);
+/// let res1 = factorize(1);
+///
+/// assert!(res0.is_empty());
+/// assert!(res1.is_empty());
+/// ```
+/// # Implementation details
+/// - Trial division is used for factors below 2<sup>10</sup>.
+/// - Primality of the rest is checked with Miller–Rabin test (from [`is_prime`]).
+/// - Composite numbers are split with Pollard's rho in Brent's variant, which multiplies
+///   differences in batches and calls Stein's [`gcd`] once per batch.
+/// - Time complexity is O(n<sup>1/4</sup>) expected.
+pub fn factorize(mut n: u64) -> Vec<(u64, u32)> {
+    let mut factors = vec![];
+
+    if n == 0 {
+        return factors;
+    }
+
+    let mut p = 2;
+    while p < TRIAL_DIVISION_BOUND && p * p <= n {
+        let mut exp = 0;
+        while n.is_multiple_of(p) {
+            n /= p;
+            exp += 1;
+        }
+
+        if exp > 0 {
+            factors.push((p, exp));
+        }
+
+        p += if p == 2 { 1 } else { 2 };
+    }
+
+    let mut composites = vec![];
+    if n > 1 {
+        composites.push(n);
+    }
+
+    let mut rng = SplitMix64(n);
+    while let Some(n) = composites.pop() {
+        if n < TRIAL_DIVISION_BOUND * TRIAL_DIVISION_BOUND || is_prime(n) {
+            factors.push((n, 1));
+            continue;
+        }
+
+        let d = pollard_brent(n, &mut rng);
+        composites.push(d);
+        composites.push(n / d);
+    }
+
+    factors.sort_unstable();
+    factors.dedup_by(|next, prev| {
+        if next.0 == prev.0 {
+            prev.1 += next.1;
+            true
+        } else {
+            false
+        }
+    });
+
+    factors
+}
+
+/// Finds a non-trivial divisor of an odd composite number with Pollard–Brent rho.
+fn pollard_brent(n: u64, rng: &mut SplitMix64) -> u64 {
+    loop {
+        let c = 1 + rng.next() % (n - 1);
+        let f = |x: u64| mod_add(mod_mul(x, x, n), c, n);
+
+        let (mut x, mut y, mut ys) = (0, rng.next() % n, 0);
+        let (mut divisor, mut r, mut product) = (1, 1, 1);
+
+        while divisor == 1 {
+            x = y;
+            for _ in 0..r {
+                y = f(y);
+            }
+
+            let mut k = 0;
+            while k < r && divisor == 1 {
+                ys = y;
+                for _ in 0..BATCH_SIZE.min(r - k) {
+                    y = f(y);
+                    product = mod_mul(product, x.abs_diff(y), n);
+                }
+
+                divisor = gcd(product, n);
+                k += BATCH_SIZE;
+            }
+
+            r *= 2;
+        }
+
+        // the batch overshot, so step back through it one by one
+        if divisor == n {
+            loop {
+                ys = f(ys);
+                divisor = gcd(x.abs_diff(ys), n);
+                if divisor > 1 {
+                    break;
+                }
+            }
+        }
+
+        if divisor != n {
+            return divisor;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trial_division(mut n: u64) -> Vec<(u64, u32)> {
+        let mut factors = vec![];
+        let mut p = 2;
+
+        while p * p <= n {
+            let mut exp = 0;
+            while n.is_multiple_of(p) {
+                n /= p;
+                exp += 1;
+            }
+            if exp > 0 {
+                factors.push((p, exp));
+            }
+            p += 1;
+        }
+
+        if n > 1 {
+            factors.push((n, 1));
+        }
+
+        factors
+    }
+
+    #[test]
+    fn factorize_matches_trial_division() {
+        for n in 1..20_000 {
+            assert_eq!(trial_division(n), factorize(n));
+        }
+
+        for n in (1u64 << 40..).step_by(1_000_003).take(200) {
+            assert_eq!(trial_division(n), factorize(n));
+        }
+    }
+
+    #[test]
+    fn factorize_random_works() {
+        let mut rng = SplitMix64(42);
+
+        for _ in 0..300 {
+            let n = rng.next() | 1 << 63;
+            let factors = factorize(n);
+
+            assert!(factors.windows(2).all(|w| w[0].0 < w[1].0));
+            assert!(factors.iter().all(|(p, _)| is_prime(*p)));
+            assert_eq!(n, factors.iter().map(|(p, e)| p.pow(*e)).product());
+        }
+    }
+
+    #[test]
+    fn factorize_works() {
+        // arrange
+        let test_suits = [
+            // Maximal value
+            (
+                u64::MAX,
+                vec![
+                    (3, 1),
+                    (5, 1),
+                    (17, 1),
+                    (257, 1),
+                    (641, 1),
+                    (65537, 1),
+                    (6700417, 1),
+                ],
+            ),
+            // Square of a 32-bit prime
+            (4_294_967_291 * 4_294_967_291, vec![(4_294_967_291, 2)]),
+            // Cube of a 21-bit prime
+            (2_097_143 * 2_097_143 * 2_097_143, vec![(2_097_143, 3)]),
+            // Power of two
+            (1 << 63, vec![(2, 63)]),
+            // Strong pseudoprime to the first 11 prime bases
+            (
+                3_825_123_056_546_413_051,
+                vec![(149_491, 1), (747_451, 1), (34_233_211, 1)],
+            ),
+            // Small and large factors
+            (
+                2 * 3 * 1_000_003 * 1_000_000_007,
+                vec![(2, 1), (3, 1), (1_000_003, 1), (1_000_000_007, 1)],
+            ),
+        ];
+
+        // act
+        let result: Vec<Vec<(u64, u32)>> = test_suits.iter().map(|t| factorize(t.0)).collect();
+
+        // assert
+        for i in 0..test_suits.len() {
+            assert_eq!(test_suits[i].1, result[i]);
+        }
+    }
+}
@@ -0,0 +1,351 @@
+use crate::v1::math::EuclideanDomain;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+/// A Gaussian integer `re + im * i`, where `i`<sup>2</sup> = -1.
+///
+/// Gaussian integers form an Euclidean domain with the norm `re`<sup>2</sup> + `im`<sup>2</sup>,
+/// so [`euclidean_gcd`](crate::v1::math::euclidean_gcd) and the related functions work with them.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::{euclidean_gcd, GaussianInt};
+///
+/// let a = GaussianInt::new(3, 4);
+/// let b = GaussianInt::new(1, -2);
+///
+/// assert_eq!(GaussianInt::new(4, 2), a + b);
+/// assert_eq!(GaussianInt::new(11, -2), a * b);
+/// assert_eq!(GaussianInt::new(-1, 2), a / b);
+/// assert_eq!(GaussianInt::new(0, 0), a % b);
+/// assert_eq!(GaussianInt::new(3, -4), a.conj());
+/// assert_eq!(25, a.norm());
+/// assert_eq!("1-2i", b.to_string());
+/// // 5 = (2 + i) * (2 - i) is not a Gaussian prime
+/// assert_eq!(GaussianInt::new(2, 1), euclidean_gcd(&GaussianInt::new(5, 0), &GaussianInt::new(2, 1)));
+/// ```
+/// ## Corner cases
+/// - The quotient is rounded to the nearest Gaussian integer (ties are rounded up),
+///   so the remainder's norm is at most half of the divisor's norm.
+/// - The canonical associate lies in the first quadrant: `re > 0` and `im >= 0`.
+/// ```
+/// use ads_rs::prelude::v1::math::{EuclideanDomain, GaussianInt};
+///
+/// let a = GaussianInt::new(7, 0);
+/// let b = GaussianInt::new(2, 0);
+///
+/// assert_eq!((GaussianInt::new(4, 0), GaussianInt::new(-1, 0)), a.div_rem(&b));
+/// assert_eq!(GaussianInt::I, GaussianInt::new(0, -3).canonical_unit());
+/// ```
+/// # Panics
+/// Operators panic on `i64` overflow like the primitive integers do, division panics if the divisor equals zero.
+/// # Implementation details
+/// - Division multiplies by the conjugate of the divisor over `i128`, then rounds both components.
+/// - Time complexity of every operation is O(1).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct GaussianInt {
+    re: i64,
+    im: i64,
+}
+
+impl GaussianInt {
+    /// The number 0.
+    pub const ZERO: Self = Self::new(0, 0);
+    /// The number 1.
+    pub const ONE: Self = Self::new(1, 0);
+    /// The imaginary unit.
+    pub const I: Self = Self::new(0, 1);
+
+    /// Creates a Gaussian integer `re + im * i`.
+    pub const fn new(re: i64, im: i64) -> Self {
+        Self { re, im }
+    }
+
+    /// Returns the real part.
+    pub fn re(&self) -> i64 {
+        self.re
+    }
+
+    /// Returns the imaginary part.
+    pub fn im(&self) -> i64 {
+        self.im
+    }
+
+    /// Returns the complex conjugate `re - im * i`.
+    pub fn conj(&self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+
+    /// Returns the norm `re`<sup>2</sup> + `im`<sup>2</sup>.
+    pub fn norm(&self) -> u128 {
+        let (re, im) = (
+            self.re.unsigned_abs() as u128,
+            self.im.unsigned_abs() as u128,
+        );
+
+        re * re + im * im
+    }
+
+    /// Raises the number to the power of `exp`, 0<sup>0</sup> equals 1.
+    pub fn pow(&self, mut exp: u32) -> Self {
+        let (mut base, mut res) = (*self, Self::ONE);
+
+        while exp > 0 {
+            if exp & 1 == 1 {
+                res = res * base;
+            }
+
+            exp >>= 1;
+
+            if exp > 0 {
+                base = base * base;
+            }
+        }
+
+        res
+    }
+}
+
+/// Divides `numer` by a positive `denom` and rounds the result to the nearest integer, ties are rounded up.
+fn round_div(numer: i128, denom: i128) -> i128 {
+    let (quot, rem) = (numer.div_euclid(denom), numer.rem_euclid(denom));
+
+    if rem >= denom - rem {
+        quot + 1
+    } else {
+        quot
+    }
+}
+
+impl EuclideanDomain for GaussianInt {
+    type Norm = u128;
+
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn one() -> Self {
+        Self::ONE
+    }
+
+    fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        assert!(*rhs != Self::ZERO, "attempt to divide by zero");
+
+        let overflow = "Gaussian integer division overflow";
+        let (a, b) = (self.re as i128, self.im as i128);
+        let (c, d) = (rhs.re as i128, rhs.im as i128);
+        let norm = i128::try_from(rhs.norm()).expect(overflow);
+
+        // (a + bi) / (c + di) = (a + bi) * (c - di) / (c^2 + d^2)
+        let re = round_div((a * c).checked_add(b * d).expect(overflow), norm);
+        let im = round_div((b * c).checked_sub(a * d).expect(overflow), norm);
+
+        let quot = Self::new(
+            i64::try_from(re).expect(overflow),
+            i64::try_from(im).expect(overflow),
+        );
+        let rem = Self::new(
+            i64::try_from(a - (re * c - im * d)).expect(overflow),
+            i64::try_from(b - (re * d + im * c)).expect(overflow),
+        );
+
+        (quot, rem)
+    }
+
+    fn norm(&self) -> Self::Norm {
+        GaussianInt::norm(self)
+    }
+
+    fn canonical_unit(&self) -> Self {
+        match (self.re, self.im) {
+            (0, 0) => Self::ONE,
+            (re, im) if re > 0 && im >= 0 => Self::ONE,
+            (re, im) if re <= 0 && im > 0 => -Self::I,
+            (re, im) if re < 0 && im <= 0 => -Self::ONE,
+            _ => Self::I,
+        }
+    }
+}
+
+impl From<i64> for GaussianInt {
+    fn from(value: i64) -> Self {
+        Self::new(value, 0)
+    }
+}
+
+impl Add for GaussianInt {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl Sub for GaussianInt {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl Mul for GaussianInt {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl Div for GaussianInt {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_rem(&rhs).0
+    }
+}
+
+impl Rem for GaussianInt {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.div_rem(&rhs).1
+    }
+}
+
+impl Neg for GaussianInt {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.re, -self.im)
+    }
+}
+
+impl Display for GaussianInt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.im < 0 {
+            write!(f, "{}-{}i", self.re, self.im.unsigned_abs())
+        } else {
+            write!(f, "{}+{}i", self.re, self.im)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v1::math::prime::SplitMix64;
+    use crate::v1::math::{euclidean_extended_gcd, euclidean_gcd, euclidean_lcm};
+
+    fn random(rng: &mut SplitMix64, bits: u32) -> GaussianInt {
+        let component = |x: u64| (x as i64) >> (64 - bits);
+
+        GaussianInt::new(component(rng.next()), component(rng.next()))
+    }
+
+    #[test]
+    fn div_rem_works() {
+        let mut rng = SplitMix64(42);
+
+        for _ in 0..1000 {
+            let a = random(&mut rng, 60);
+            let bits = 1 + (rng.next() % 60) as u32;
+            let b = random(&mut rng, bits);
+
+            if b == GaussianInt::ZERO {
+                continue;
+            }
+
+            let (q, r) = a.div_rem(&b);
+
+            assert_eq!(a, q * b + r, "a = {a}, b = {b}");
+            assert!(2 * r.norm() <= b.norm(), "a = {a}, b = {b}");
+        }
+    }
+
+    #[test]
+    fn canonical_unit_works() {
+        // arrange
+        let test_suits = [
+            // zero
+            (GaussianInt::ZERO, GaussianInt::ONE),
+            // positive real axis
+            (GaussianInt::new(5, 0), GaussianInt::ONE),
+            // first quadrant
+            (GaussianInt::new(2, 3), GaussianInt::ONE),
+            // positive imaginary axis
+            (GaussianInt::new(0, 5), -GaussianInt::I),
+            // second quadrant
+            (GaussianInt::new(-2, 3), -GaussianInt::I),
+            // third quadrant
+            (GaussianInt::new(-2, -3), -GaussianInt::ONE),
+            // fourth quadrant
+            (GaussianInt::new(2, -3), GaussianInt::I),
+        ];
+
+        // act
+        let result: Vec<GaussianInt> = test_suits.iter().map(|t| t.0.canonical_unit()).collect();
+
+        // assert
+        for i in 0..test_suits.len() {
+            assert_eq!(test_suits[i].1, result[i]);
+            let (z, canonical) = (test_suits[i].0, result[i] * test_suits[i].0);
+            assert!(z == GaussianInt::ZERO || (canonical.re() > 0 && canonical.im() >= 0));
+        }
+    }
+
+    #[test]
+    fn gcd_works() {
+        let mut rng = SplitMix64(42);
+
+        for _ in 0..1000 {
+            let c = random(&mut rng, 8);
+            let a = c * random(&mut rng, 20);
+            let b = c * random(&mut rng, 20);
+            let (g, x, y) = euclidean_extended_gcd(&a, &b);
+
+            assert_eq!(g, euclidean_gcd(&a, &b));
+            assert_eq!(g, x * a + y * b, "a = {a}, b = {b}");
+            assert_eq!(GaussianInt::ONE, g.canonical_unit());
+
+            if g != GaussianInt::ZERO {
+                assert_eq!(GaussianInt::ZERO, a % g);
+                assert_eq!(GaussianInt::ZERO, b % g);
+            }
+
+            if c != GaussianInt::ZERO {
+                assert_eq!(GaussianInt::ZERO, g % c);
+            }
+        }
+    }
+
+    #[test]
+    fn lcm_works() {
+        let mut rng = SplitMix64(42);
+
+        for _ in 0..1000 {
+            let (a, b) = (random(&mut rng, 12), random(&mut rng, 12));
+            let lcm = euclidean_lcm(&a, &b);
+
+            assert_eq!(
+                a.norm() * b.norm(),
+                lcm.norm() * euclidean_gcd(&a, &b).norm()
+            );
+
+            if lcm != GaussianInt::ZERO {
+                assert_eq!(GaussianInt::ZERO, lcm % a);
+                assert_eq!(GaussianInt::ZERO, lcm % b);
+            }
+        }
+    }
+
+    #[test]
+    fn display_works() {
+        assert_eq!("3+4i", GaussianInt::new(3, 4).to_string());
+        assert_eq!("-3-4i", GaussianInt::new(-3, -4).to_string());
+        assert_eq!("0+0i", GaussianInt::ZERO.to_string());
+        assert_eq!(GaussianInt::new(-7, 24), GaussianInt::new(3, 4).pow(2));
+    }
+}
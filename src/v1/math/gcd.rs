@@ -1,4 +1,6 @@
+use crate::v1::math::Integer;
 use std::mem::{replace, swap};
+use std::ops::{Mul, Sub};
 
 /// Finds the GCD (Greatest Common Divisor) for an array of elements.
 /// # Examples
@@ -13,9 +15,20 @@ use std::mem::{replace, swap};
 /// assert_eq!(1, res1);
 /// assert_eq!(5, res2);
 /// ```
+/// Any primitive integer type is accepted, the result of signed numbers is non-negative.
+/// ```
+/// use ads_rs::prelude::v1::math::gcd_many;
+///
+/// let res0 = gcd_many(&[u128::MAX - 1, 1u128 << 100]);
+/// let res1 = gcd_many(&[-42i32, 8, -144]);
+///
+/// assert_eq!(2, res0);
+/// assert_eq!(2, res1);
+/// ```
 /// ## Corner cases
 /// - GCD of an empty array equals 0.
-/// - GCD of a single element array equals that element.
+/// - GCD of a single element array equals that element (its absolute value for signed numbers).
+/// - GCD which equals `2`<sup>`BITS - 1`</sup> isn't representable by a signed type and wraps to `T::MIN`.
 /// ```
 /// use ads_rs::prelude::v1::math::gcd_many;
 ///
@@ -30,39 +43,44 @@ use std::mem::{replace, swap};
 /// - Time complexity: O(K * N<sup>2</sup>) where:
 ///     - N - bits count in the biggest number.
 ///     - K - number's count
-pub fn gcd_many(elems: &[u64]) -> u64 {
+pub fn gcd_many<T: Integer>(elems: &[T]) -> T {
     if elems.is_empty() {
-        return 0;
+        return T::ZERO;
     }
 
     if elems.len() == 1 {
-        return elems[0];
+        return T::from_unsigned(elems[0].unsigned_abs());
     }
 
-    elems.iter().fold(0, |acc, e| {
-        let (mut lhs, mut rhs) = (acc, *e);
-
-        if lhs == 0 || rhs == 0 {
-            return lhs | rhs;
-        }
+    T::from_unsigned(
+        elems
+            .iter()
+            .fold(T::Unsigned::ZERO, |acc, e| stein(acc, e.unsigned_abs())),
+    )
+}
 
-        // find common factor of 2
-        let shift = (lhs | rhs).trailing_zeros();
+/// Stein's (binary GCD) algorithm for a pair of unsigned numbers.
+fn stein<U: Integer>(mut lhs: U, mut rhs: U) -> U {
+    if lhs == U::ZERO || rhs == U::ZERO {
+        return lhs | rhs;
+    }
 
-        // divide lhs and rhs by 2 until odd
-        rhs >>= rhs.trailing_zeros();
-        while lhs > 0 {
-            lhs >>= lhs.trailing_zeros();
+    // find common factor of 2
+    let shift = (lhs | rhs).trailing_zeros();
 
-            if rhs > lhs {
-                swap(&mut lhs, &mut rhs);
-            }
+    // divide lhs and rhs by 2 until odd
+    rhs >>= rhs.trailing_zeros();
+    while lhs > U::ZERO {
+        lhs >>= lhs.trailing_zeros();
 
-            lhs -= rhs
+        if rhs > lhs {
+            swap(&mut lhs, &mut rhs);
         }
 
-        rhs << shift
-    })
+        lhs -= rhs
+    }
+
+    rhs << shift
 }
 
 /// Finds an extended GCD (Greatest Common Divisor) for a pair of numbers.  
@@ -83,9 +101,21 @@ pub fn gcd_many(elems: &[u64]) -> u64 {
 /// assert_eq!((5, -2, 1), res1);
 /// assert_eq!((7, -1, 6), res2);
 /// ```
+/// Coefficients have the signed type of the same width as the arguments.
+/// ```
+/// use ads_rs::prelude::v1::math::extended_gcd;
+///
+/// let res0: (u32, i32, i32) = extended_gcd(30u32, 20u32);
+/// let res1: (u8, i8, i8) = extended_gcd(161u8, 28u8);
+///
+/// assert_eq!((10, 1, -1), res0);
+/// assert_eq!((7, -1, 6), res1);
+/// ```
 /// ## Corner case
 /// - Result of `extended_gcd(0, 0)` equals tuple `(0, 1, 0)`.
-/// - Negative numbers is not supported, but implementation allows it.
+/// - Negative numbers is not supported, but implementation allows it (the GCD may come out negative).
+/// - Coefficients are computed in `T::Signed` and may overflow for arguments close to `T::MAX`,
+///   use [`extended_gcd_wide`] or [`extended_gcd_signed`] when the whole 64-bit domain is needed.
 /// ```
 /// use ads_rs::prelude::v1::math::extended_gcd;
 ///
@@ -95,25 +125,288 @@ pub fn gcd_many(elems: &[u64]) -> u64 {
 /// ```
 /// # Implementation details
 /// - Euclid's algorithm used, because its extended version is faster than Stein's algorithm
+/// - The loop is shared with [`euclidean_extended_gcd`](crate::v1::math::euclidean_extended_gcd),
+///   remainders are computed in `T` and coefficients in `T::Signed`.
 /// - Time complexity is O(log<sub>2</sub>(min(lhs, rhs)))
-pub fn extended_gcd(lhs: u64, rhs: u64) -> (u64, i64, i64) {
-    let (mut x, mut y) = (1, 0);
-    let (mut x1, mut y1, mut lhs1, mut rhs1) = (0i64, 1i64, lhs, rhs);
+pub fn extended_gcd<T: Integer>(lhs: T, rhs: T) -> (T, T::Signed, T::Signed) {
+    extended_euclid(
+        lhs,
+        rhs,
+        [T::Signed::ONE, T::Signed::ZERO],
+        |r| *r == T::ZERO,
+        |a, b| ((*a / *b).to_signed(), *a % *b),
+    )
+}
 
-    while rhs1 > 0 {
-        let q = lhs1 / rhs1;
+/// Extended Euclid's algorithm shared by [`extended_gcd`] and
+/// [`euclidean_extended_gcd`](crate::v1::math::euclidean_extended_gcd).
+/// Remainders have the type `R`, while the coefficients have the type `C`, so unsigned numbers
+/// can have signed coefficients; `div_rem` returns the quotient already converted into `C`.
+/// The coefficients of the last (zero) remainder aren't computed, so they can't overflow.
+pub(crate) fn extended_euclid<R, C>(
+    mut lhs: R,
+    mut rhs: R,
+    [one, zero]: [C; 2],
+    is_zero: impl Fn(&R) -> bool,
+    div_rem: impl Fn(&R, &R) -> (C, R),
+) -> (R, C, C)
+where
+    C: Clone + Sub<Output = C> + Mul<Output = C>,
+{
+    let (mut x, mut y) = (one.clone(), zero.clone());
+    let (mut x1, mut y1) = (zero, one);
+
+    while !is_zero(&rhs) {
+        let (q, rem) = div_rem(&lhs, &rhs);
+
+        if is_zero(&rem) {
+            return (rhs, x1, y1);
+        }
 
-        let new_x1 = x - (q as i64) * x1;
+        let new_x1 = x - q.clone() * x1.clone();
         x = replace(&mut x1, new_x1);
 
-        let new_y1 = y - (q as i64) * y1;
+        let new_y1 = y - q * y1.clone();
         y = replace(&mut y1, new_y1);
 
-        let new_rhs1 = lhs1 - q * rhs1;
-        lhs1 = replace(&mut rhs1, new_rhs1);
+        lhs = replace(&mut rhs, rem);
     }
 
-    (lhs1, x, y)
+    (lhs, x, y)
+}
+
+/// Finds an extended GCD (Greatest Common Divisor) for a pair of numbers with wide coefficients.
+/// Unlike [`extended_gcd`], coefficients `x` and `y` such that the equality
+///
+/// x * lhs + y * rhs = gcd(lhs, rhs)
+///
+/// holds are guaranteed to be correct for the whole `u64` domain.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::extended_gcd_wide;
+///
+/// let res0 = extended_gcd_wide(161, 28);
+/// let res1 = extended_gcd_wide(u64::MAX, u64::MAX - 1);
+///
+/// assert_eq!((7, -1, 6), res0);
+/// assert_eq!((1, 1, -1), res1);
+/// ```
+/// ## Corner case
+/// Result of `extended_gcd_wide(0, 0)` equals tuple `(0, 1, 0)`.
+/// ```
+/// use ads_rs::prelude::v1::math::extended_gcd_wide;
+///
+/// let res = extended_gcd_wide(0, 0);
+///
+/// assert_eq!((0, 1, 0), res);
+/// ```
+/// # Implementation details
+/// - Euclid's algorithm from [`extended_gcd`] is performed over `u128`/`i128`,
+///   so neither quotients nor intermediate coefficients can overflow.
+/// - Time complexity is O(log<sub>2</sub>(min(lhs, rhs)))
+pub fn extended_gcd_wide(lhs: u64, rhs: u64) -> (u64, i128, i128) {
+    let (gcd, x, y) = extended_gcd(lhs as u128, rhs as u128);
+
+    (gcd as u64, x, y)
+}
+
+/// Finds an extended GCD (Greatest Common Divisor) for a pair of signed numbers.
+/// The GCD is normalized to be non-negative, and coefficients `x` and `y` satisfy
+///
+/// x * lhs + y * rhs = gcd(lhs, rhs)
+///
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::extended_gcd_signed;
+///
+/// let res0 = extended_gcd_signed(-161, 28);
+/// let res1 = extended_gcd_signed(15, -35);
+/// let res2 = extended_gcd_signed(-30, -20);
+///
+/// assert_eq!((7, 1, 6), res0);
+/// assert_eq!((5, -2, -1), res1);
+/// assert_eq!((10, -1, 1), res2);
+/// ```
+/// ## Corner cases
+/// - Result of `extended_gcd_signed(0, 0)` equals tuple `(0, 1, 0)`.
+/// - GCD is returned as `u64`, because `gcd(i64::MIN, 0)` doesn't fit into `i64`.
+/// ```
+/// use ads_rs::prelude::v1::math::extended_gcd_signed;
+///
+/// let res0 = extended_gcd_signed(0, 0);
+/// let res1 = extended_gcd_signed(i64::MIN, 0);
+///
+/// assert_eq!((0, 1, 0), res0);
+/// assert_eq!((1 << 63, -1, 0), res1);
+/// ```
+/// # Implementation details
+/// - [`extended_gcd_wide`] is applied to the absolute values, then the coefficients' signs are fixed.
+/// - Time complexity is O(log<sub>2</sub>(min(|lhs|, |rhs|)))
+pub fn extended_gcd_signed(lhs: i64, rhs: i64) -> (u64, i128, i128) {
+    let (gcd, x, y) = extended_gcd_wide(lhs.unsigned_abs(), rhs.unsigned_abs());
+
+    (
+        gcd,
+        if lhs < 0 { -x } else { x },
+        if rhs < 0 { -y } else { y },
+    )
+}
+
+/// Finds an extended GCD (Greatest Common Divisor) for an array of elements.
+/// "Extended" means that algorithm will return not only GCD, but coefficients `c`<sub>`i`</sub> such that the equality
+///
+/// c<sub>0</sub> * elems<sub>0</sub> + c<sub>1</sub> * elems<sub>1</sub> + ... = gcd(elems)
+///
+/// holds.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::extended_gcd_many;
+///
+/// let res0 = extended_gcd_many(&[6, 10, 15]);
+/// let res1 = extended_gcd_many(&[30, 20]);
+///
+/// assert_eq!((1, vec![-14, 7, 1]), res0);
+/// assert_eq!((10, vec![1, -1]), res1);
+/// ```
+/// ## Corner cases
+/// - Result of an empty array equals `(0, [])`.
+/// - Result of an array of zeros equals `(0, [1, 0, ...])`, like `extended_gcd(0, 0)`.
+/// ```
+/// use ads_rs::prelude::v1::math::extended_gcd_many;
+///
+/// let res0 = extended_gcd_many(&[]);
+/// let res1 = extended_gcd_many(&[0, 0, 0]);
+///
+/// assert_eq!((0, vec![]), res0);
+/// assert_eq!((0, vec![1, 0, 0]), res1);
+/// ```
+/// # Panics
+/// Coefficients of naive chaining may grow exponentially with the elements count,
+/// so the function panics if they don't fit into `i128`. Use [`extended_gcd_many_reduced`] to avoid it.
+/// # Implementation details
+/// - [`extended_gcd_wide`] is chained over the prefix GCDs:
+///   `gcd(g, e) = s * g + t * e`, so every previous coefficient is multiplied by `s`.
+/// - Time complexity: O(K * log<sub>2</sub>(M)) where:
+///     - M - the biggest number.
+///     - K - number's count
+pub fn extended_gcd_many(elems: &[u64]) -> (u64, Vec<i128>) {
+    if elems.is_empty() {
+        return (0, vec![]);
+    }
+
+    let mut gcd = elems[0];
+    let mut steps = Vec::with_capacity(elems.len());
+    steps.push((1, 1));
+
+    for &e in &elems[1..] {
+        let (new_gcd, s, t) = extended_gcd_wide(gcd, e);
+        gcd = new_gcd;
+        steps.push((s, t));
+    }
+
+    // coefficient of the i-th element is t_i multiplied by every later s
+    let mut coeffs = vec![0; elems.len()];
+    let mut multiplier: i128 = 1;
+    for (i, &(s, t)) in steps.iter().enumerate().rev() {
+        coeffs[i] = multiplier
+            .checked_mul(t)
+            .expect("coefficients overflow i128");
+        multiplier = multiplier
+            .checked_mul(s)
+            .expect("coefficients overflow i128");
+    }
+
+    (gcd, coeffs)
+}
+
+/// Finds an extended GCD (Greatest Common Divisor) for an array of elements with small coefficients.
+/// Same as [`extended_gcd_many`], but the coefficients never overflow:
+/// - `|c`<sub>`i`</sub>`| <= max(elems) / 2` for every element except the biggest one (the pivot).
+/// - `|c`<sub>`pivot`</sub>`| <= sum(elems) / 2 + K` where K - number's count.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::extended_gcd_many_reduced;
+///
+/// let res0 = extended_gcd_many_reduced(&[6, 10, 15]);
+/// let res1 = extended_gcd_many_reduced(&[30, 20]);
+///
+/// assert_eq!((1, vec![1, 1, -1]), res0);
+/// assert_eq!((10, vec![1, -1]), res1);
+/// ```
+/// ## Corner cases
+/// Same as for [`extended_gcd_many`].
+/// ```
+/// use ads_rs::prelude::v1::math::extended_gcd_many_reduced;
+///
+/// let res0 = extended_gcd_many_reduced(&[]);
+/// let res1 = extended_gcd_many_reduced(&[0, 0, 0]);
+///
+/// assert_eq!((0, vec![]), res0);
+/// assert_eq!((0, vec![1, 0, 0]), res1);
+/// ```
+/// # Implementation details
+/// - The biggest element is chosen as a pivot and processed first, its coefficient is restored at the end.
+/// - Every other coefficient `c`<sub>`i`</sub> is kept reduced modulo `pivot / gcd(elems`<sub>`i`</sub>`, pivot)`
+///   into the symmetric range, which adds a multiple of `pivot` to the sum only.
+/// - Time complexity: O(K * log<sub>2</sub>(M) * min(K, log<sub>2</sub>(M))) where:
+///     - M - the biggest number.
+///     - K - number's count
+pub fn extended_gcd_many_reduced(elems: &[u64]) -> (u64, Vec<i128>) {
+    let pivot = match elems.iter().enumerate().max_by_key(|(_, e)| **e) {
+        Some((i, e)) if *e > 0 => i,
+        _ => return extended_gcd_many(elems),
+    };
+    let pivot_value = elems[pivot] as u128;
+
+    // modulus of every coefficient, so that adding it to the coefficient changes the sum by a multiple of pivot
+    let moduli: Vec<u128> = elems
+        .iter()
+        .map(|e| pivot_value / gcd(*e as u128, pivot_value))
+        .collect();
+    let reduce = |value: i128, m: u128| -> i128 {
+        let value = value.rem_euclid(m as i128);
+        if value > (m / 2) as i128 {
+            value - m as i128
+        } else {
+            value
+        }
+    };
+
+    let mut gcd = elems[pivot];
+    let mut coeffs = vec![0i128; elems.len()];
+
+    for (j, &e) in elems.iter().enumerate() {
+        if j == pivot {
+            continue;
+        }
+
+        let (new_gcd, s, t) = extended_gcd_wide(gcd, e);
+        gcd = new_gcd;
+
+        if s != 1 {
+            for i in (0..j).filter(|i| *i != pivot) {
+                let m = moduli[i];
+                let c = coeffs[i].rem_euclid(m as i128) as u128;
+                let s = s.rem_euclid(m as i128) as u128;
+                // both are less than 2^64, so the product fits into u128
+                coeffs[i] = reduce((c * s % m) as i128, m);
+            }
+        }
+
+        coeffs[j] = reduce(t, moduli[j]);
+    }
+
+    // restore pivot coefficient from the identity, splitting every product into quotient and remainder
+    let (mut quotients, mut remainders) = (0i128, 0i128);
+    for (i, &e) in elems.iter().enumerate() {
+        // |c| <= pivot / 2 and e < 2^64, so the product fits into i128
+        let product = coeffs[i] * e as i128;
+        quotients += product.div_euclid(pivot_value as i128);
+        remainders += product.rem_euclid(pivot_value as i128);
+    }
+    coeffs[pivot] = (gcd as i128 - remainders) / pivot_value as i128 - quotients;
+
+    (gcd, coeffs)
 }
 
 /// Finds an GCD (Greatest Common Divisor) for a pair of numbers.
@@ -138,14 +431,116 @@ pub fn extended_gcd(lhs: u64, rhs: u64) -> (u64, i64, i64) {
 ///
 /// assert_eq!(0, res);
 /// ```
+/// See [`gcd_many`] for the handling of signed numbers.
 /// # Implementation details
 /// - Stein's algorithm used (from [`gcd_many`]).
 /// - Time complexity: O(N<sup>2</sup>) where N - number of bits in the biggest number.
 #[inline]
-pub fn gcd(lhs: u64, rhs: u64) -> u64 {
+pub fn gcd<T: Integer>(lhs: T, rhs: T) -> T {
     gcd_many(&[lhs, rhs])
 }
 
+/// Finds an GCD (Greatest Common Divisor) for a pair of numbers at compile time.
+/// Same as [`gcd`], but can be used in `const` contexts.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::const_gcd;
+///
+/// const RES: u64 = const_gcd(42, 144);
+/// // reduce a fixed-point ratio at compile time
+/// const NUM: u64 = 48_000 / const_gcd(48_000, 44_100);
+/// const DEN: u64 = 44_100 / const_gcd(48_000, 44_100);
+/// let table = [0u8; const_gcd(12, 18) as usize];
+///
+/// assert_eq!(6, RES);
+/// assert_eq!((160, 147), (NUM, DEN));
+/// assert_eq!(6, table.len());
+/// ```
+/// ## Corner case
+/// GCD of both zero numbers equals 0.
+/// ```
+/// use ads_rs::prelude::v1::math::const_gcd;
+///
+/// const RES: u64 = const_gcd(0, 0);
+///
+/// assert_eq!(0, RES);
+/// ```
+/// # Implementation details
+/// - Stein's algorithm used.
+/// - Time complexity: O(N<sup>2</sup>) where N - number of bits in the biggest number.
+pub const fn const_gcd(mut lhs: u64, mut rhs: u64) -> u64 {
+    if lhs == 0 || rhs == 0 {
+        return lhs | rhs;
+    }
+
+    // find common factor of 2
+    let shift = (lhs | rhs).trailing_zeros();
+
+    // divide lhs and rhs by 2 until odd
+    rhs >>= rhs.trailing_zeros();
+    while lhs > 0 {
+        lhs >>= lhs.trailing_zeros();
+
+        if rhs > lhs {
+            let tmp = lhs;
+            lhs = rhs;
+            rhs = tmp;
+        }
+
+        lhs -= rhs
+    }
+
+    rhs << shift
+}
+
+/// Finds an extended GCD (Greatest Common Divisor) for a pair of numbers at compile time.
+/// Same as [`extended_gcd`], but can be used in `const` contexts.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::const_extended_gcd;
+///
+/// const RES0: (u64, i64, i64) = const_extended_gcd(161, 28);
+/// const RES1: (u64, i64, i64) = const_extended_gcd(u64::MAX, u64::MAX - 1);
+///
+/// assert_eq!((7, -1, 6), RES0);
+/// assert_eq!((1, 1, -1), RES1);
+/// ```
+/// ## Corner case
+/// Result of `const_extended_gcd(0, 0)` equals tuple `(0, 1, 0)`.
+/// ```
+/// use ads_rs::prelude::v1::math::const_extended_gcd;
+///
+/// const RES: (u64, i64, i64) = const_extended_gcd(0, 0);
+///
+/// assert_eq!((0, 1, 0), RES);
+/// ```
+/// # Implementation details
+/// - Euclid's algorithm used with `i128` coefficients, so nothing overflows;
+///   the resulting coefficients are bounded by `max(lhs, rhs) / 2` and always fit into `i64`.
+/// - Time complexity is O(log<sub>2</sub>(min(lhs, rhs)))
+pub const fn const_extended_gcd(lhs: u64, rhs: u64) -> (u64, i64, i64) {
+    let (mut x, mut y) = (1i128, 0i128);
+    let (mut x1, mut y1, mut lhs1, mut rhs1) = (0i128, 1i128, lhs, rhs);
+
+    while rhs1 > 0 {
+        let q = lhs1 / rhs1;
+
+        let new_x1 = x - (q as i128) * x1;
+        x = x1;
+        x1 = new_x1;
+
+        let new_y1 = y - (q as i128) * y1;
+        y = y1;
+        y1 = new_y1;
+
+        let new_rhs1 = lhs1 - q * rhs1;
+        lhs1 = rhs1;
+        rhs1 = new_rhs1;
+    }
+
+    (lhs1, x as i64, y as i64)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -175,6 +570,218 @@ mod tests {
             assert_eq!(test_suits[i].2, result[i]);
         }
     }
+    #[test]
+    fn extended_gcd_generic_works() {
+        // arrange
+        let test_suits = [
+            // Regular case
+            (2048u128, 48u128, (16u128, -1i128, 43i128)),
+            // Relative prime
+            (2052, 617, (1, 132, -439)),
+            // Wide numbers
+            (1 << 100, 3 << 98, (1 << 98, 1, -1)),
+        ];
+
+        // act
+        let result: Vec<(u128, i128, i128)> =
+            test_suits.iter().map(|t| extended_gcd(t.0, t.1)).collect();
+
+        // assert
+        for i in 0..test_suits.len() {
+            assert_eq!(test_suits[i].2, result[i]);
+        }
+    }
+
+    #[test]
+    fn extended_gcd_wide_works() {
+        // arrange
+        let test_suits = [
+            (u64::MAX, u64::MAX - 1),
+            (u64::MAX, 1),
+            (u64::MAX - 1, u64::MAX / 3),
+            (u64::MAX, 1 << 63),
+            (9_223_372_036_854_775_837, 18_446_744_073_709_551_557),
+            (12_345_678_901_234_567_890, 9_876_543_210_987_654_321),
+            (0, u64::MAX),
+        ];
+
+        // act
+        let result: Vec<(u64, i128, i128)> = test_suits
+            .iter()
+            .map(|t| extended_gcd_wide(t.0, t.1))
+            .collect();
+
+        // assert
+        for i in 0..test_suits.len() {
+            let (lhs, rhs) = test_suits[i];
+            let (g, x, y) = result[i];
+            assert_eq!(gcd(lhs, rhs), g);
+            assert_eq!(g as i128, x * lhs as i128 + y * rhs as i128);
+        }
+    }
+
+    #[test]
+    fn extended_gcd_signed_works() {
+        // arrange
+        let test_suits = [
+            (i64::MIN, i64::MAX),
+            (i64::MIN, i64::MIN),
+            (i64::MAX, -2),
+            (-1, 0),
+            (0, -12),
+            (-2048, -48),
+        ];
+
+        // act
+        let result: Vec<(u64, i128, i128)> = test_suits
+            .iter()
+            .map(|t| extended_gcd_signed(t.0, t.1))
+            .collect();
+
+        // assert
+        for i in 0..test_suits.len() {
+            let (lhs, rhs) = test_suits[i];
+            let (g, x, y) = result[i];
+            assert_eq!(gcd(lhs.unsigned_abs(), rhs.unsigned_abs()), g);
+            assert_eq!(g as i128, x * lhs as i128 + y * rhs as i128);
+        }
+    }
+
+    fn assert_bezout(elems: &[u64], result: &(u64, Vec<i128>)) {
+        assert_eq!(gcd_many(elems), result.0);
+        assert_eq!(elems.len(), result.1.len());
+
+        // terms may be close to i128 bounds, but the exact sum is small
+        let sum = elems.iter().zip(&result.1).fold(0i128, |acc, (e, c)| {
+            acc.wrapping_add(c.wrapping_mul(*e as i128))
+        });
+        assert_eq!(result.0 as i128, sum);
+    }
+
+    #[test]
+    fn extended_gcd_many_works() {
+        // arrange
+        let test_suits = [
+            vec![],
+            vec![0],
+            vec![17],
+            vec![0, 0, 5],
+            vec![5, 0, 0],
+            vec![12, 18, 8],
+            vec![2048, 48, 617],
+            vec![u64::MAX, u64::MAX - 1],
+        ];
+
+        // act
+        let result: Vec<(u64, Vec<i128>)> =
+            test_suits.iter().map(|t| extended_gcd_many(t)).collect();
+
+        // assert
+        for i in 0..test_suits.len() {
+            assert_bezout(&test_suits[i], &result[i]);
+        }
+    }
+
+    #[test]
+    fn extended_gcd_many_reduced_works() {
+        // arrange
+        let test_suits = [
+            vec![],
+            vec![0],
+            vec![17],
+            vec![0, 0, 5],
+            vec![5, 0, 0],
+            vec![12, 18, 8],
+            vec![2048, 48, 617],
+            vec![u64::MAX, u64::MAX - 1, u64::MAX - 2, u64::MAX / 3],
+            (1..=64).map(|i| u64::MAX / 3 - i * i * 1_000_003).collect(),
+            (0..63).map(|i| (1 << 63) + (1 << i) + 1).collect(),
+        ];
+
+        // act
+        let result: Vec<(u64, Vec<i128>)> = test_suits
+            .iter()
+            .map(|t| extended_gcd_many_reduced(t))
+            .collect();
+
+        // assert
+        for i in 0..test_suits.len() {
+            assert_bezout(&test_suits[i], &result[i]);
+
+            let max = test_suits[i].iter().max().copied().unwrap_or(0) as i128;
+            let sum = test_suits[i].iter().map(|e| *e as i128).sum::<i128>();
+            let len = test_suits[i].len() as i128;
+            for c in &result[i].1 {
+                assert!(c.abs() <= max / 2 || c.abs() <= sum / 2 + len);
+            }
+        }
+    }
+
+    #[test]
+    fn extended_gcd_many_reduced_keeps_coefficients_small() {
+        // naive chaining overflows on these elements: every prefix GCD loses one prime only
+        let primes = [3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47];
+        let product: u64 = primes.iter().product();
+        let elems: Vec<u64> = primes.iter().map(|p| product / p).collect();
+
+        let result = extended_gcd_many_reduced(&elems);
+
+        assert_bezout(&elems, &result);
+        assert!(std::panic::catch_unwind(|| extended_gcd_many(&elems)).is_err());
+    }
+
+    #[test]
+    fn const_fns_match_runtime() {
+        let values = [
+            0,
+            1,
+            2,
+            3,
+            12,
+            18,
+            617,
+            2048,
+            2052,
+            u64::MAX / 3,
+            u64::MAX - 1,
+            u64::MAX,
+        ];
+
+        for lhs in values {
+            for rhs in values {
+                let (g, x, y) = extended_gcd_wide(lhs, rhs);
+
+                assert_eq!(gcd(lhs, rhs), const_gcd(lhs, rhs));
+                assert_eq!((g, x as i64, y as i64), const_extended_gcd(lhs, rhs));
+            }
+        }
+    }
+
+    #[test]
+    fn gcd_signed_works() {
+        // arrange
+        let test_suits = [
+            // Negative lhs
+            (-48i64, 18i64, 6i64),
+            // Negative both
+            (-48, -18, 6),
+            // Zero and negative
+            (0, -7, 7),
+            // Zero and minimal value
+            (0, i64::MIN, i64::MIN),
+            // Minimal value and odd number
+            (i64::MIN, 3, 1),
+        ];
+
+        // act
+        let result: Vec<i64> = test_suits.iter().map(|t| gcd(t.0, t.1)).collect();
+
+        // assert
+        for i in 0..test_suits.len() {
+            assert_eq!(test_suits[i].2, result[i]);
+        }
+    }
+
     #[test]
     fn gcd_many_works() {
         // arrange
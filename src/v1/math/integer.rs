@@ -0,0 +1,276 @@
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+use std::ops::{
+    Add, AddAssign, BitAnd, BitOr, BitXor, Div, DivAssign, Mul, MulAssign, Not, Rem, RemAssign,
+    Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+};
+
+/// An abstraction over the primitive integer types used by the [`math`](crate::v1::math) module.
+///
+/// The trait is implemented for every primitive integer: `u8`, `u16`, `u32`, `u64`, `u128`,
+/// `usize`, `i8`, `i16`, `i32`, `i64`, `i128` and `isize`. It exposes only the operations
+/// the algorithms need, so they can be written once and used with any width and signedness.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::Integer;
+///
+/// fn double<T: Integer>(x: T) -> T {
+///     x + x
+/// }
+///
+/// assert_eq!(42u8, double(21u8));
+/// assert_eq!(-42i128, double(-21i128));
+/// assert_eq!(u64::MAX, <u64 as Integer>::MAX);
+/// assert_eq!(7, (-7i32).unsigned_abs());
+/// ```
+/// # Implementation details
+/// - Every type has an unsigned and a signed counterpart of the same width
+///   ([`Integer::Unsigned`] and [`Integer::Signed`]), e.g. `u64` and `i64` for both `u64` and `i64`.
+/// - The `to_*`/`from_*` conversions between the counterparts reinterpret bits, like the `as` operator.
+pub trait Integer:
+    Copy
+    + Ord
+    + Eq
+    + Hash
+    + Default
+    + Debug
+    + Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+    + RemAssign
+    + BitAnd<Output = Self>
+    + BitOr<Output = Self>
+    + BitXor<Output = Self>
+    + Not<Output = Self>
+    + Shl<u32, Output = Self>
+    + Shr<u32, Output = Self>
+    + ShlAssign<u32>
+    + ShrAssign<u32>
+{
+    /// The unsigned integer type of the same width.
+    type Unsigned: Integer<Unsigned = Self::Unsigned, Signed = Self::Signed>;
+    /// The signed integer type of the same width.
+    type Signed: Integer<Unsigned = Self::Unsigned, Signed = Self::Signed>;
+
+    /// The additive identity.
+    const ZERO: Self;
+    /// The multiplicative identity.
+    const ONE: Self;
+    /// The smallest value of the type.
+    const MIN: Self;
+    /// The largest value of the type.
+    const MAX: Self;
+    /// The size of the type in bits.
+    const BITS: u32;
+    /// Whether the type is able to hold negative values.
+    const SIGNED: bool;
+
+    /// Returns the number of trailing zeros in the binary representation.
+    fn trailing_zeros(self) -> u32;
+    /// Returns the number of leading zeros in the binary representation.
+    fn leading_zeros(self) -> u32;
+    /// Returns `true` if the value is less than zero.
+    fn is_negative(self) -> bool;
+    /// Returns the absolute value as the unsigned counterpart, without overflow.
+    fn unsigned_abs(self) -> Self::Unsigned;
+    /// Reinterprets the bits as the unsigned counterpart.
+    fn to_unsigned(self) -> Self::Unsigned;
+    /// Reinterprets the bits of the unsigned counterpart as `Self`.
+    fn from_unsigned(value: Self::Unsigned) -> Self;
+    /// Reinterprets the bits as the signed counterpart.
+    fn to_signed(self) -> Self::Signed;
+    /// Reinterprets the bits of the signed counterpart as `Self`.
+    fn from_signed(value: Self::Signed) -> Self;
+    /// Checked addition, `None` on overflow.
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    /// Checked subtraction, `None` on overflow.
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    /// Checked multiplication, `None` on overflow.
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    /// Checked division, `None` if `rhs == 0` or on overflow.
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+    /// Checked remainder, `None` if `rhs == 0` or on overflow.
+    fn checked_rem(self, rhs: Self) -> Option<Self>;
+    /// Checked negation, `None` on overflow (and for any non-zero unsigned value).
+    fn checked_neg(self) -> Option<Self>;
+    /// Wrapping (modular) addition.
+    fn wrapping_add(self, rhs: Self) -> Self;
+    /// Wrapping (modular) subtraction.
+    fn wrapping_sub(self, rhs: Self) -> Self;
+    /// Wrapping (modular) multiplication.
+    fn wrapping_mul(self, rhs: Self) -> Self;
+    /// Wrapping (modular) negation.
+    fn wrapping_neg(self) -> Self;
+}
+
+macro_rules! impl_integer {
+    ($signed:expr, $unsigned_t:ty, $signed_t:ty; $($t:ty),+) => {$(
+        impl Integer for $t {
+            type Unsigned = $unsigned_t;
+            type Signed = $signed_t;
+
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            const MIN: Self = <$t>::MIN;
+            const MAX: Self = <$t>::MAX;
+            const BITS: u32 = <$t>::BITS;
+            const SIGNED: bool = $signed;
+
+            #[inline]
+            fn trailing_zeros(self) -> u32 {
+                <$t>::trailing_zeros(self)
+            }
+
+            #[inline]
+            fn leading_zeros(self) -> u32 {
+                <$t>::leading_zeros(self)
+            }
+
+            #[inline]
+            #[allow(unused_comparisons)]
+            fn is_negative(self) -> bool {
+                self < 0
+            }
+
+            #[inline]
+            #[allow(unused_comparisons)]
+            fn unsigned_abs(self) -> Self::Unsigned {
+                if self < 0 {
+                    (self as $unsigned_t).wrapping_neg()
+                } else {
+                    self as $unsigned_t
+                }
+            }
+
+            #[inline]
+            fn to_unsigned(self) -> Self::Unsigned {
+                self as $unsigned_t
+            }
+
+            #[inline]
+            fn from_unsigned(value: Self::Unsigned) -> Self {
+                value as $t
+            }
+
+            #[inline]
+            fn to_signed(self) -> Self::Signed {
+                self as $signed_t
+            }
+
+            #[inline]
+            fn from_signed(value: Self::Signed) -> Self {
+                value as $t
+            }
+
+            #[inline]
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_add(self, rhs)
+            }
+
+            #[inline]
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_sub(self, rhs)
+            }
+
+            #[inline]
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+
+            #[inline]
+            fn checked_div(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_div(self, rhs)
+            }
+
+            #[inline]
+            fn checked_rem(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_rem(self, rhs)
+            }
+
+            #[inline]
+            fn checked_neg(self) -> Option<Self> {
+                <$t>::checked_neg(self)
+            }
+
+            #[inline]
+            fn wrapping_add(self, rhs: Self) -> Self {
+                <$t>::wrapping_add(self, rhs)
+            }
+
+            #[inline]
+            fn wrapping_sub(self, rhs: Self) -> Self {
+                <$t>::wrapping_sub(self, rhs)
+            }
+
+            #[inline]
+            fn wrapping_mul(self, rhs: Self) -> Self {
+                <$t>::wrapping_mul(self, rhs)
+            }
+
+            #[inline]
+            fn wrapping_neg(self) -> Self {
+                <$t>::wrapping_neg(self)
+            }
+        }
+    )+};
+}
+
+impl_integer!(false, u8, i8; u8);
+impl_integer!(false, u16, i16; u16);
+impl_integer!(false, u32, i32; u32);
+impl_integer!(false, u64, i64; u64);
+impl_integer!(false, u128, i128; u128);
+impl_integer!(false, usize, isize; usize);
+impl_integer!(true, u8, i8; i8);
+impl_integer!(true, u16, i16; i16);
+impl_integer!(true, u32, i32; i32);
+impl_integer!(true, u64, i64; i64);
+impl_integer!(true, u128, i128; i128);
+impl_integer!(true, usize, isize; isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversions_work() {
+        // arrange
+        let test_suits = [
+            // Positive value
+            (5i8, 5u8, 5u8),
+            // Negative value
+            (-5i8, 5u8, 251u8),
+            // Minimal value
+            (i8::MIN, 128u8, 128u8),
+            // Zero
+            (0i8, 0u8, 0u8),
+        ];
+
+        // act
+        let result: Vec<(u8, u8, i8)> = test_suits
+            .iter()
+            .map(|t| {
+                (
+                    t.0.unsigned_abs(),
+                    t.0.to_unsigned(),
+                    i8::from_unsigned(t.0.to_unsigned()),
+                )
+            })
+            .collect();
+
+        // assert
+        for i in 0..test_suits.len() {
+            assert_eq!(
+                (test_suits[i].1, test_suits[i].2, test_suits[i].0),
+                result[i]
+            );
+        }
+    }
+}
@@ -0,0 +1,374 @@
+use crate::v1::math::Integer;
+
+/// Finds the integer square root of a number: the largest `r` such that `r`<sup>`2`</sup>` <= n`.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::isqrt;
+///
+/// let res0 = isqrt(99u64);
+/// let res1 = isqrt(u64::MAX);
+/// let res2 = isqrt(u128::MAX);
+///
+/// assert_eq!(9, res0);
+/// assert_eq!(u32::MAX as u64, res1);
+/// assert_eq!(u64::MAX as u128, res2);
+/// ```
+/// ## Corner case
+/// Unlike `(n as f64).sqrt()`, the result is exact near the type bounds.
+/// ```
+/// use ads_rs::prelude::v1::math::isqrt;
+///
+/// let n = (u32::MAX as u64) * (u32::MAX as u64) - 1;
+///
+/// assert_eq!(4_294_967_295, (n as f64).sqrt() as u64);
+/// assert_eq!(4_294_967_294, isqrt(n));
+/// ```
+/// # Panics
+/// Panics if `n` is negative.
+/// # Implementation details
+/// - Same as [`iroot`] with `k = 2`.
+/// - Time complexity is O(log<sub>2</sub>(N)) where N - bits count of `n`.
+#[inline]
+pub fn isqrt<T: Integer>(n: T) -> T {
+    iroot(n, 2)
+}
+
+/// Finds the integer cube root of a number: the largest `r` such that `r`<sup>`3`</sup>` <= n`.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::icbrt;
+///
+/// let res0 = icbrt(26u8);
+/// let res1 = icbrt(27u8);
+/// let res2 = icbrt(u64::MAX);
+///
+/// assert_eq!(2, res0);
+/// assert_eq!(3, res1);
+/// assert_eq!(2_642_245, res2);
+/// ```
+/// # Panics
+/// Panics if `n` is negative.
+/// # Implementation details
+/// - Same as [`iroot`] with `k = 3`.
+/// - Time complexity is O(log<sub>2</sub>(N)) where N - bits count of `n`.
+#[inline]
+pub fn icbrt<T: Integer>(n: T) -> T {
+    iroot(n, 3)
+}
+
+/// Finds the integer `k`-th root of a number: the largest `r` such that `r`<sup>`k`</sup>` <= n`.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::iroot;
+///
+/// let res0 = iroot(1_000_000u32, 6);
+/// let res1 = iroot(u64::MAX, 5);
+/// let res2 = iroot(3u128.pow(80) - 1, 40);
+///
+/// assert_eq!(10, res0);
+/// assert_eq!(7131, res1);
+/// assert_eq!(8, res2);
+/// ```
+/// Signed types are accepted for non-negative numbers.
+/// ```
+/// use ads_rs::prelude::v1::math::iroot;
+///
+/// assert_eq!(13, iroot(i16::MAX, 4));
+/// ```
+/// ## Corner cases
+/// - The first root of a number is the number itself.
+/// - Roots of 0 and 1 are 0 and 1 respectively.
+/// - Roots with `k` above the bits count of `n` equal 1 for any positive `n`.
+/// ```
+/// use ads_rs::prelude::v1::math::iroot;
+///
+/// assert_eq!(u128::MAX, iroot(u128::MAX, 1));
+/// assert_eq!(0, iroot(0u64, 7));
+/// assert_eq!(1, iroot(u64::MAX, 64));
+/// assert_eq!(1, iroot(u64::MAX, u32::MAX));
+/// ```
+/// # Panics
+/// Panics if `k == 0` or `n` is negative.
+/// # Implementation details
+/// - Newton's method in integers `r := r - ⌈(r - n / r`<sup>`k - 1`</sup>`) / k⌉` converges from above,
+///   the initial value is the power of two with `⌈N / k⌉` bits, which is not less than the root.
+/// - Powers are checked for overflow, so the whole range of every type is supported.
+/// - Time complexity is O(log<sub>2</sub>(N) * log<sub>2</sub>(k)) where N - bits count of `n`.
+pub fn iroot<T: Integer>(n: T, k: u32) -> T {
+    assert!(k != 0, "zeroth root is undefined");
+    assert!(!n.is_negative(), "root of a negative number");
+
+    let bits = T::BITS - n.leading_zeros();
+    if k == 1 || n <= T::ONE {
+        return n;
+    }
+    if k >= bits {
+        return T::ONE;
+    }
+
+    // k < bits <= 128, so it fits into every type
+    let k_t = small::<T>(k);
+
+    let mut root = T::ONE << bits.div_ceil(k);
+    loop {
+        let quotient = checked_pow(root, k - 1).map_or(T::ZERO, |power| n / power);
+        if quotient >= root {
+            return root;
+        }
+
+        let diff = root - quotient;
+        root -= (diff + k_t - T::ONE) / k_t;
+    }
+}
+
+/// Checks whether a number is a perfect square.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::is_perfect_square;
+///
+/// let res0 = is_perfect_square(144u32);
+/// let res1 = is_perfect_square(145u32);
+/// let res2 = is_perfect_square((u64::MAX as u128) * (u64::MAX as u128));
+///
+/// assert!(res0);
+/// assert!(!res1);
+/// assert!(res2);
+/// ```
+/// ## Corner case
+/// 0 and 1 are perfect squares.
+/// ```
+/// use ads_rs::prelude::v1::math::is_perfect_square;
+///
+/// assert!(is_perfect_square(0u8));
+/// assert!(is_perfect_square(1u8));
+/// ```
+/// # Panics
+/// Panics if `n` is negative.
+/// # Implementation details
+/// - Squares take only 12 values modulo 64, so most numbers are rejected without the root.
+/// - The root is found with [`isqrt`].
+/// - Time complexity is O(log<sub>2</sub>(N)) where N - bits count of `n`.
+pub fn is_perfect_square<T: Integer>(n: T) -> bool {
+    assert!(!n.is_negative(), "root of a negative number");
+
+    // bit i of the mask is set if i is a square modulo 64
+    const SQUARES_MOD_64: u64 = 0x0202_0212_0203_0213;
+    if (SQUARES_MOD_64 >> low_bits(n, 6)) & 1 == 0 {
+        return false;
+    }
+
+    let root = isqrt(n);
+    root * root == n
+}
+
+/// Finds the representation of a number as a perfect power `base`<sup>`exp`</sup> with the largest exponent `exp >= 2`.
+/// Returns `None` if the number isn't a perfect power.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::perfect_power;
+///
+/// let res0 = perfect_power(64u32);
+/// let res1 = perfect_power(3u64.pow(40));
+/// let res2 = perfect_power(72u32);
+/// let res3 = perfect_power(u128::MAX);
+///
+/// assert_eq!(Some((2, 6)), res0);
+/// assert_eq!(Some((3, 40)), res1);
+/// assert_eq!(None, res2);
+/// assert_eq!(None, res3);
+/// ```
+/// ## Corner case
+/// 0 and 1 are any powers of themselves, so `None` is returned for them.
+/// ```
+/// use ads_rs::prelude::v1::math::perfect_power;
+///
+/// assert_eq!(None, perfect_power(0u64));
+/// assert_eq!(None, perfect_power(1u64));
+/// ```
+/// # Panics
+/// Panics if `n` is negative.
+/// # Implementation details
+/// - Exponents are checked from the largest possible one (`N - 1` for N bits), roots are found with [`iroot`].
+/// - Time complexity is O(N * log<sub>2</sub>(N)<sup>2</sup>) where N - bits count of `n`.
+pub fn perfect_power<T: Integer>(n: T) -> Option<(T, u32)> {
+    assert!(!n.is_negative(), "root of a negative number");
+
+    if n <= T::ONE {
+        return None;
+    }
+
+    let bits = T::BITS - n.leading_zeros();
+    (2..bits).rev().find_map(|exp| {
+        let base = iroot(n, exp);
+        (checked_pow(base, exp) == Some(n)).then_some((base, exp))
+    })
+}
+
+/// Converts a number below 2<sup>7</sup> into any integer type.
+fn small<T: Integer>(value: u32) -> T {
+    (0..7)
+        .filter(|&i| (value >> i) & 1 == 1)
+        .fold(T::ZERO, |acc, i| acc | (T::ONE << i))
+}
+
+/// Returns the lowest `count` bits of a non-negative number.
+fn low_bits<T: Integer>(n: T, count: u32) -> u32 {
+    (0..count)
+        .filter(|&i| (n >> i) & T::ONE == T::ONE)
+        .fold(0, |acc, i| acc | (1 << i))
+}
+
+/// Raises a number to a power, `None` on overflow.
+fn checked_pow<T: Integer>(mut base: T, mut exp: u32) -> Option<T> {
+    let mut res = T::ONE;
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            res = res.checked_mul(base)?;
+        }
+
+        exp >>= 1;
+        if exp > 0 {
+            base = base.checked_mul(base)?;
+        }
+    }
+
+    Some(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v1::math::prime::SplitMix64;
+
+    fn is_root(n: u128, k: u32, root: u128) -> bool {
+        let fits = |r: u128| r.checked_pow(k).is_some_and(|power| power <= n);
+
+        fits(root) && !fits(root + 1)
+    }
+
+    #[test]
+    fn isqrt_works() {
+        for n in [
+            0,
+            1,
+            2,
+            3,
+            4,
+            15,
+            16,
+            17,
+            u32::MAX as u64,
+            u64::MAX,
+            u64::MAX - 1,
+        ] {
+            let root = isqrt(n) as u128;
+            assert!(root * root <= n as u128 && (root + 1) * (root + 1) > n as u128);
+        }
+    }
+
+    #[test]
+    fn iroot_works_for_all_widths() {
+        for n in 0..=u8::MAX {
+            for k in 1..=9 {
+                let expected = (0..=n).rev().find(|r| is_root(n as u128, k, *r as u128));
+                assert_eq!(expected, Some(iroot(n, k)), "n = {n}, k = {k}");
+            }
+        }
+
+        for n in 0..=u16::MAX {
+            assert!(is_root(n as u128, 2, isqrt(n) as u128), "n = {n}");
+            assert!(is_root(n as u128, 3, icbrt(n) as u128), "n = {n}");
+        }
+
+        assert_eq!(181, isqrt(i16::MAX));
+        assert_eq!(1_290, icbrt(i32::MAX));
+        assert_eq!(3_037_000_499, isqrt(i64::MAX));
+        assert_eq!(u32::MAX as usize, isqrt(usize::MAX));
+    }
+
+    #[test]
+    fn iroot_works_near_powers() {
+        let mut rng = SplitMix64(42);
+
+        for k in 1..=130 {
+            for _ in 0..200 {
+                let n = (rng.next() as u128) << 64 | rng.next() as u128;
+                let n = n >> (rng.next() % 128);
+                let root = iroot(n, k);
+                assert!(is_root(n, k, root), "n = {n}, k = {k}");
+
+                // exact powers and their neighbours
+                if let Some(power) = root.checked_pow(k) {
+                    assert_eq!(root, iroot(power, k));
+                    if power > 0 {
+                        assert_eq!(root - 1, iroot(power - 1, k));
+                    }
+                }
+
+                let n64 = n as u64;
+                assert!(
+                    is_root(n64 as u128, k, iroot(n64, k) as u128),
+                    "n = {n64}, k = {k}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn is_perfect_square_matches_isqrt() {
+        for n in 0..=u16::MAX as u32 * 4 {
+            let root = isqrt(n);
+            assert_eq!(root * root == n, is_perfect_square(n), "n = {n}");
+        }
+    }
+
+    #[test]
+    fn perfect_power_works() {
+        // arrange
+        let test_suits = [
+            // Square
+            (49u128, Some((7, 2))),
+            // Largest exponent is chosen
+            (1 << 12, Some((2, 12))),
+            (6u128.pow(10), Some((6, 10))),
+            // Mixed exponents
+            (72u128.pow(2) * 2, None),
+            // Largest prime power
+            (3u128.pow(80), Some((3, 80))),
+            // Largest square
+            ((u64::MAX as u128).pow(2), Some((u64::MAX as u128, 2))),
+            // Product of distinct primes
+            (4_294_967_291 * 4_294_967_279, None),
+            // Prime
+            (18_446_744_073_709_551_557, None),
+        ];
+
+        // act
+        let result: Vec<Option<(u128, u32)>> =
+            test_suits.iter().map(|t| perfect_power(t.0)).collect();
+
+        // assert
+        for i in 0..test_suits.len() {
+            assert_eq!(test_suits[i].1, result[i]);
+        }
+    }
+
+    #[test]
+    fn perfect_power_matches_brute_force() {
+        let mut expected = vec![None; u16::MAX as usize + 1];
+
+        // smaller bases are written later, so they win with larger exponents
+        for base in (2..=u8::MAX as u16).rev() {
+            let mut exp = 2;
+            while let Some(power) = base.checked_pow(exp) {
+                expected[power as usize] = Some((base, exp));
+                exp += 1;
+            }
+        }
+
+        for n in 0..=u16::MAX {
+            assert_eq!(expected[n as usize], perfect_power(n), "n = {n}");
+        }
+    }
+}
@@ -0,0 +1,369 @@
+use crate::v1::math::gcd;
+
+/// Finds the sum of `⌊(a * i + b) / m⌋` over `i` in `[0, n)`.
+/// Returns `None` if the sum doesn't fit into `i128`.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::floor_sum;
+///
+/// // 0 + 2 + 3 + 4
+/// let res0 = floor_sum(4, 3, 4, 2);
+/// // -1 + -2 + -4
+/// let res1 = floor_sum(3, 3, -5, -1);
+/// let res2 = floor_sum(u64::MAX, u64::MAX, i64::MAX, i64::MAX);
+///
+/// assert_eq!(Some(9), res0);
+/// assert_eq!(Some(-7), res1);
+/// assert_eq!(Some(85_070_591_730_234_615_847_396_907_784_232_501_249), res2);
+/// ```
+/// ## Corner cases
+/// - Sum of an empty range equals 0.
+/// - Division rounds down (towards negative infinity) for negative numerators.
+/// ```
+/// use ads_rs::prelude::v1::math::floor_sum;
+///
+/// assert_eq!(Some(0), floor_sum(0, 7, 3, 5));
+/// assert_eq!(Some(-1), floor_sum(1, 2, 0, -1));
+/// assert_eq!(None, floor_sum(u64::MAX, 1, i64::MAX, 0));
+/// ```
+/// # Panics
+/// Panics if `m == 0`.
+/// # Implementation details
+/// - `a` and `b` are reduced modulo `m`, and the rest of the sum counts lattice points under the line,
+///   which are counted again with swapped axes: `(n, m, a, b)` turns into `(⌊(a * n + b) / m⌋, a, m, (a * n + b) mod m)`.
+/// - The pair `(m, a)` goes through the same steps as in Euclid's [`gcd`].
+/// - Time complexity is O(log<sub>2</sub>(min(a, m))).
+pub fn floor_sum(n: u64, m: u64, a: i64, b: i64) -> Option<i128> {
+    assert!(m != 0, "divisor equals 0");
+
+    let (m_i, n_i) = (m as i128, n as i128);
+    let (qa, ra) = ((a as i128).div_euclid(m_i), (a as i128).rem_euclid(m_i));
+    let (qb, rb) = ((b as i128).div_euclid(m_i), (b as i128).rem_euclid(m_i));
+
+    // ⌊(a * i + b) / m⌋ = qa * i + qb + ⌊(ra * i + rb) / m⌋
+    let partial = i128::try_from(triangle(n as u128))
+        .ok()?
+        .checked_mul(qa)?
+        .checked_add(qb.checked_mul(n_i)?)?;
+    let rest = floor_sum_unsigned(n as u128, m as u128, ra as u128, rb as u128)?;
+
+    partial.checked_add_unsigned(rest)
+}
+
+/// Counts lattice points `(x, y)` with `x, y >= 0` under the line `a * x + b * y = c` (on the line included).
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::count_points_under_line;
+///
+/// // (0, 0), (1, 0), (2, 0), (0, 1)
+/// let res0 = count_points_under_line(1, 2, 2);
+/// let res1 = count_points_under_line(3, 5, 1_000_000_007);
+/// let res2 = count_points_under_line(1, 1, u64::MAX);
+///
+/// assert_eq!(4, res0);
+/// assert_eq!(33_333_334_100_000_004, res1);
+/// assert_eq!(170_141_183_460_469_231_740_910_675_752_738_881_536, res2);
+/// ```
+/// ## Corner case
+/// The origin is the only point for `c == 0`.
+/// ```
+/// use ads_rs::prelude::v1::math::count_points_under_line;
+///
+/// assert_eq!(1, count_points_under_line(5, 7, 0));
+/// ```
+/// # Panics
+/// Panics if `a == 0` or `b == 0`, because the count is infinite.
+/// # Implementation details
+/// - Points are counted by columns `x = c / a - i`, so the count is [`floor_sum`]`(c / a + 1, b, a, c mod a) + c / a + 1`.
+/// - Time complexity is O(log<sub>2</sub>(min(a, b))).
+pub fn count_points_under_line(a: u64, b: u64, c: u64) -> u128 {
+    assert!(a != 0 && b != 0, "count of points is infinite");
+
+    let columns = (c / a) as u128 + 1;
+
+    // a * (c / a - i) + b * y <= c  ⟺  y <= (a * i + c mod a) / b
+    floor_sum_unsigned(columns, b as u128, a as u128, (c % a) as u128).unwrap() + columns
+}
+
+/// Counts lattice points on the segment between two points (ends included).
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::segment_lattice_points;
+///
+/// let res0 = segment_lattice_points((0, 0), (6, 4));
+/// let res1 = segment_lattice_points((-3, 7), (-3, -2));
+/// let res2 = segment_lattice_points((i64::MIN, 0), (i64::MAX, 0));
+///
+/// assert_eq!(3, res0);
+/// assert_eq!(10, res1);
+/// assert_eq!(1 << 64, res2);
+/// ```
+/// ## Corner case
+/// A degenerate segment has a single point.
+/// ```
+/// use ads_rs::prelude::v1::math::segment_lattice_points;
+///
+/// assert_eq!(1, segment_lattice_points((2, 5), (2, 5)));
+/// ```
+/// # Implementation details
+/// - The segment is split by lattice points into `gcd(|dx|, |dy|)` equal parts (from [`gcd`]).
+/// - Time complexity: O(N<sup>2</sup>) where N - number of bits in coordinates.
+pub fn segment_lattice_points(from: (i64, i64), to: (i64, i64)) -> u128 {
+    edge_lattice_points(from, to) as u128 + 1
+}
+
+/// Counts lattice points on the boundary of a polygon given by its vertices in order (clockwise or counter-clockwise).
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::polygon_boundary_points;
+///
+/// let res0 = polygon_boundary_points(&[(0, 0), (4, 0), (4, 3), (0, 3)]);
+/// let res1 = polygon_boundary_points(&[(0, 0), (6, 4), (2, 10)]);
+///
+/// assert_eq!(14, res0);
+/// assert_eq!(6, res1);
+/// ```
+/// ## Corner case
+/// A polygon without vertices has no points, a single vertex is a single point.
+/// ```
+/// use ads_rs::prelude::v1::math::polygon_boundary_points;
+///
+/// assert_eq!(0, polygon_boundary_points(&[]));
+/// assert_eq!(1, polygon_boundary_points(&[(3, 3)]));
+/// ```
+/// # Implementation details
+/// - Every edge contributes `gcd(|dx|, |dy|)` points, excluding its end (see [`segment_lattice_points`]).
+/// - Time complexity is O(K * N<sup>2</sup>) where:
+///     - N - number of bits in coordinates.
+///     - K - vertices count.
+pub fn polygon_boundary_points(vertices: &[(i64, i64)]) -> u128 {
+    match vertices.len() {
+        0 => 0,
+        1 => 1,
+        len => (0..len)
+            .map(|i| edge_lattice_points(vertices[i], vertices[(i + 1) % len]) as u128)
+            .sum(),
+    }
+}
+
+/// Counts lattice points strictly inside a simple polygon given by its vertices in order (clockwise or counter-clockwise).
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::polygon_interior_points;
+///
+/// let res0 = polygon_interior_points(&[(0, 0), (4, 0), (4, 3), (0, 3)]);
+/// let res1 = polygon_interior_points(&[(0, 0), (6, 4), (2, 10)]);
+/// let res2 = polygon_interior_points(&[(0, 0), (2, 0), (2, 2), (1, 1), (0, 2)]);
+///
+/// assert_eq!(6, res0);
+/// assert_eq!(24, res1);
+/// assert_eq!(0, res2);
+/// ```
+/// ## Corner case
+/// Degenerate polygons with zero area have no interior points.
+/// ```
+/// use ads_rs::prelude::v1::math::polygon_interior_points;
+///
+/// assert_eq!(0, polygon_interior_points(&[(0, 0), (5, 5), (10, 10)]));
+/// assert_eq!(0, polygon_interior_points(&[]));
+/// ```
+/// # Panics
+/// Panics if the doubled area or its partial sums overflow `i128`,
+/// which is impossible for coordinates fitting into `i32`.
+/// # Implementation details
+/// - Pick's theorem `A = I + B / 2 - 1` is used, where `2A` is found with the shoelace formula
+///   and `B` with [`polygon_boundary_points`].
+/// - Time complexity is O(K * N<sup>2</sup>) where:
+///     - N - number of bits in coordinates.
+///     - K - vertices count.
+pub fn polygon_interior_points(vertices: &[(i64, i64)]) -> u128 {
+    if vertices.len() < 3 {
+        return 0;
+    }
+
+    let boundary = polygon_boundary_points(vertices);
+
+    // I = (2A - B + 2) / 2
+    (doubled_area(vertices) + 2).saturating_sub(boundary) / 2
+}
+
+/// Finds the doubled area of a polygon with the shoelace formula.
+fn doubled_area(vertices: &[(i64, i64)]) -> u128 {
+    let (x0, y0) = (vertices[0].0 as i128, vertices[0].1 as i128);
+
+    vertices
+        .windows(2)
+        .try_fold(0i128, |acc, w| {
+            let (x1, y1) = (w[0].0 as i128 - x0, w[0].1 as i128 - y0);
+            let (x2, y2) = (w[1].0 as i128 - x0, w[1].1 as i128 - y0);
+
+            acc.checked_add(x1.checked_mul(y2)?.checked_sub(x2.checked_mul(y1)?)?)
+        })
+        .expect("area overflows i128")
+        .unsigned_abs()
+}
+
+/// Counts lattice points on a segment excluding one of its ends.
+fn edge_lattice_points(from: (i64, i64), to: (i64, i64)) -> u64 {
+    let dx = (to.0 as i128 - from.0 as i128).unsigned_abs() as u64;
+    let dy = (to.1 as i128 - from.1 as i128).unsigned_abs() as u64;
+
+    gcd(dx, dy)
+}
+
+/// Finds `n * (n - 1) / 2`.
+fn triangle(n: u128) -> u128 {
+    if n.is_multiple_of(2) {
+        n / 2 * n.saturating_sub(1)
+    } else {
+        (n - 1) / 2 * n
+    }
+}
+
+/// [`floor_sum`] for `a < m` and `b < m`, `None` on overflow.
+fn floor_sum_unsigned(mut n: u128, mut m: u128, mut a: u128, mut b: u128) -> Option<u128> {
+    let mut res = 0u128;
+
+    loop {
+        if a >= m {
+            res = res.checked_add(triangle(n).checked_mul(a / m)?)?;
+            a %= m;
+        }
+        if b >= m {
+            res = res.checked_add(n.checked_mul(b / m)?)?;
+            b %= m;
+        }
+
+        // a, b < m < 2^64 and n < 2^64, so the line ends below 2^128
+        let y_max = a * n + b;
+        if y_max < m {
+            return Some(res);
+        }
+
+        (n, b) = (y_max / m, y_max % m);
+        (m, a) = (a, m);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v1::math::prime::SplitMix64;
+
+    fn brute_force_floor_sum(n: u64, m: u64, a: i64, b: i64) -> i128 {
+        (0..n as i128)
+            .map(|i| (a as i128 * i + b as i128).div_euclid(m as i128))
+            .sum()
+    }
+
+    #[test]
+    fn floor_sum_matches_brute_force() {
+        for n in 0..20 {
+            for m in 1..20 {
+                for a in -25..25 {
+                    for b in -25..25 {
+                        assert_eq!(
+                            Some(brute_force_floor_sum(n, m, a, b)),
+                            floor_sum(n, m, a, b),
+                            "n = {n}, m = {m}, a = {a}, b = {b}"
+                        );
+                    }
+                }
+            }
+        }
+
+        let mut rng = SplitMix64(42);
+        for _ in 0..1000 {
+            let (n, m) = (rng.next() % 1000, rng.next() >> (rng.next() % 64) | 1);
+            let (a, b) = (rng.next() as i64, rng.next() as i64);
+
+            assert_eq!(
+                Some(brute_force_floor_sum(n, m, a, b)),
+                floor_sum(n, m, a, b),
+                "n = {n}, m = {m}, a = {a}, b = {b}"
+            );
+        }
+    }
+
+    #[test]
+    fn count_points_under_line_matches_brute_force() {
+        for a in 1..15 {
+            for b in 1..15 {
+                for c in 0..60 {
+                    let expected = (0..=c / a).map(|x| (c - a * x) / b + 1).sum::<u64>();
+                    assert_eq!(
+                        expected as u128,
+                        count_points_under_line(a, b, c),
+                        "a = {a}, b = {b}, c = {c}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn polygon_interior_points_matches_brute_force() {
+        let mut rng = SplitMix64(42);
+
+        for _ in 0..500 {
+            let mut coord = || (rng.next() % 21) as i64 - 10;
+            let triangle = [(coord(), coord()), (coord(), coord()), (coord(), coord())];
+
+            let cross = |p: (i64, i64), q: (i64, i64), r: (i64, i64)| {
+                (q.0 - p.0) * (r.1 - p.1) - (q.1 - p.1) * (r.0 - p.0)
+            };
+            let (mut inside, mut boundary) = (0, 0);
+            for x in -10..=10 {
+                for y in -10..=10 {
+                    let signs = [0, 1, 2]
+                        .map(|i| cross(triangle[i], triangle[(i + 1) % 3], (x, y)).signum());
+                    let on_edge = (0..3).any(|i| {
+                        let (p, q) = (triangle[i], triangle[(i + 1) % 3]);
+                        signs[i] == 0
+                            && p.0.min(q.0) <= x
+                            && x <= p.0.max(q.0)
+                            && p.1.min(q.1) <= y
+                            && y <= p.1.max(q.1)
+                    });
+
+                    if on_edge {
+                        boundary += 1;
+                    } else if signs.iter().all(|&s| s > 0) || signs.iter().all(|&s| s < 0) {
+                        inside += 1;
+                    }
+                }
+            }
+
+            assert_eq!(inside, polygon_interior_points(&triangle), "{triangle:?}");
+            if doubled_area(&triangle) > 0 {
+                assert_eq!(boundary, polygon_boundary_points(&triangle), "{triangle:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn segment_lattice_points_works() {
+        // arrange
+        let test_suits = [
+            // Horizontal segment
+            (((0, 0), (5, 0)), 6),
+            // Diagonal segment
+            (((-2, -2), (3, 3)), 6),
+            // Coprime deltas
+            (((0, 0), (5, 3)), 2),
+            // Widest segment
+            (((i64::MIN, i64::MIN), (i64::MAX, i64::MAX)), 1 << 64),
+        ];
+
+        // act
+        let result: Vec<u128> = test_suits
+            .iter()
+            .map(|t| segment_lattice_points(t.0 .0, t.0 .1))
+            .collect();
+
+        // assert
+        for i in 0..test_suits.len() {
+            assert_eq!(test_suits[i].1, result[i]);
+        }
+    }
+}
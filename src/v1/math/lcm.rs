@@ -1,4 +1,4 @@
-use crate::v1::math::gcd_many;
+use crate::v1::math::{const_gcd, gcd, Integer};
 
 /// Finds the LCM (Least Common Multiple) for an array of elements.
 /// # Examples
@@ -9,44 +9,39 @@ use crate::v1::math::gcd_many;
 /// let res1 = lcm_many(&[89, 144, 233, 377, 610]);
 /// let res2 = lcm_many(&[25, 105, 235, 100]);
 ///
-/// assert_eq!(24192, res0);
-/// assert_eq!(686719856160, res1);
-/// assert_eq!(12337500, res2);
+/// assert_eq!(1008, res0);
+/// assert_eq!(343359928080, res1);
+/// assert_eq!(98700, res2);
 /// ```
 /// ## Corner cases
 /// - LCM of an empty array equals 0.
 /// - LCM of a single element array equals that element.
+/// - LCM of an array containing 0 equals 0.
 /// ```
 /// use ads_rs::prelude::v1::math::lcm_many;
 ///
 /// let res0 = lcm_many(&[]);
 /// let res1 = lcm_many(&[25]);
+/// let res2 = lcm_many(&[25, 0, 4]);
 ///
 /// assert_eq!(0, res0);
 /// assert_eq!(25, res1);
+/// assert_eq!(0, res2);
+/// ```
+/// # Panics
+/// Panics if the LCM doesn't fit into `u64`, use [`checked_lcm_many`] or [`lcm_many_wide`] to handle it.
+/// ```should_panic
+/// use ads_rs::prelude::v1::math::lcm_many;
+///
+/// lcm_many(&[u64::MAX, u64::MAX - 1]);
 /// ```
 /// # Implementation details
-/// - Stein's algorithm is used to find GCD (from [`gcd_many`]).
+/// - LCM is accumulated pairwise (from [`checked_lcm_many`]).
 /// - Time complexity: O(K * N<sup>2</sup>) where:
 ///     - N - bits count in the biggest number.
 ///     - K - number's count
 pub fn lcm_many(elems: &[u64]) -> u64 {
-    if elems.is_empty() {
-        return 0;
-    }
-
-    if elems.len() == 1 {
-        return elems[0];
-    }
-
-    let gcd = gcd_many(elems);
-
-    // GCD is zero only when all elements are zeros
-    if gcd == 0 {
-        return 0;
-    }
-
-    elems[1..].iter().fold(elems[0] / gcd, |acc, e| acc * (*e))
+    checked_lcm_many(elems).expect("LCM overflows u64")
 }
 
 /// Finds an LCM (Least Common Multiple) for a pair of numbers.
@@ -71,14 +66,179 @@ pub fn lcm_many(elems: &[u64]) -> u64 {
 ///
 /// assert_eq!(0, res);
 /// ```
+/// # Panics
+/// Panics if the LCM doesn't fit into `u64`, use [`checked_lcm`] to handle it.
 /// # Implementation details
-/// - Stein's algorithm used (from [`gcd_many`]).
+/// - Stein's algorithm used (from [`gcd_many`](crate::v1::math::gcd_many)).
 /// - Time complexity: O(N<sup>2</sup>) where N - number of bits in the biggest number.
 #[inline]
 pub fn lcm(lhs: u64, rhs: u64) -> u64 {
     lcm_many(&[lhs, rhs])
 }
 
+/// Finds the LCM (Least Common Multiple) for an array of elements, returning `None` on overflow.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::checked_lcm_many;
+///
+/// let res0 = checked_lcm_many(&[42u64, 8, 144]);
+/// let res1 = checked_lcm_many(&[u64::MAX, u64::MAX - 1]);
+/// let res2 = checked_lcm_many(&[-4i8, 6, 10]);
+/// let res3 = checked_lcm_many(&[100i8, 3]);
+///
+/// assert_eq!(Some(1008), res0);
+/// assert_eq!(None, res1);
+/// assert_eq!(Some(60), res2);
+/// assert_eq!(None, res3);
+/// ```
+/// ## Corner cases
+/// - LCM of an empty array equals 0.
+/// - LCM of a single element array equals that element (its absolute value for signed numbers).
+/// - LCM of an array containing 0 equals 0.
+/// ```
+/// use ads_rs::prelude::v1::math::checked_lcm_many;
+///
+/// let res0 = checked_lcm_many::<u64>(&[]);
+/// let res1 = checked_lcm_many(&[25u64]);
+/// let res2 = checked_lcm_many(&[u64::MAX, u64::MAX - 1, 0]);
+///
+/// assert_eq!(Some(0), res0);
+/// assert_eq!(Some(25), res1);
+/// assert_eq!(Some(0), res2);
+/// ```
+/// # Implementation details
+/// - LCM is accumulated pairwise as `lcm(acc, e) = acc / gcd(acc, e) * e`,
+///   so intermediate values never exceed the result.
+/// - Stein's algorithm is used to find GCD (from [`gcd_many`](crate::v1::math::gcd_many)).
+/// - Time complexity: O(K * N<sup>2</sup>) where:
+///     - N - bits count in the biggest number.
+///     - K - number's count
+pub fn checked_lcm_many<T: Integer>(elems: &[T]) -> Option<T> {
+    // zero makes the whole LCM zero, even if the other elements overflow
+    if elems.is_empty() || elems.contains(&T::ZERO) {
+        return Some(T::ZERO);
+    }
+
+    elems.iter().try_fold(T::ONE, |acc, e| checked_lcm(acc, *e))
+}
+
+/// Finds an LCM (Least Common Multiple) for a pair of numbers, returning `None` on overflow.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::checked_lcm;
+///
+/// let res0 = checked_lcm(42u64, 144);
+/// let res1 = checked_lcm(1u64 << 63, 3);
+/// let res2 = checked_lcm(-4i32, 6);
+///
+/// assert_eq!(Some(1008), res0);
+/// assert_eq!(None, res1);
+/// assert_eq!(Some(12), res2);
+/// ```
+/// ## Corner case
+/// LCM of zero and any number equals 0.
+/// ```
+/// use ads_rs::prelude::v1::math::checked_lcm;
+///
+/// let res = checked_lcm(0u64, u64::MAX);
+///
+/// assert_eq!(Some(0), res);
+/// ```
+/// # Implementation details
+/// - Stein's algorithm used (from [`gcd_many`](crate::v1::math::gcd_many)).
+/// - Time complexity: O(N<sup>2</sup>) where N - number of bits in the biggest number.
+pub fn checked_lcm<T: Integer>(lhs: T, rhs: T) -> Option<T> {
+    let (lhs, rhs) = (lhs.unsigned_abs(), rhs.unsigned_abs());
+
+    if lhs == T::Unsigned::ZERO || rhs == T::Unsigned::ZERO {
+        return Some(T::ZERO);
+    }
+
+    let res = T::from_unsigned((lhs / gcd(lhs, rhs)).checked_mul(rhs)?);
+
+    // the unsigned result may not fit into a signed type
+    if res.is_negative() {
+        None
+    } else {
+        Some(res)
+    }
+}
+
+/// Finds the LCM (Least Common Multiple) for an array of `u64` elements as `u128`.
+/// Returns `None` only if the LCM doesn't fit even into `u128`.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::lcm_many_wide;
+///
+/// let res0 = lcm_many_wide(&[42, 8, 144]);
+/// let res1 = lcm_many_wide(&[u64::MAX, u64::MAX - 1]);
+/// let res2 = lcm_many_wide(&[u64::MAX, u64::MAX - 1, u64::MAX - 2]);
+///
+/// assert_eq!(Some(1008), res0);
+/// assert_eq!(Some(u64::MAX as u128 * (u64::MAX - 1) as u128), res1);
+/// assert_eq!(None, res2);
+/// ```
+/// ## Corner cases
+/// Same as for [`checked_lcm_many`].
+/// # Implementation details
+/// - Elements are widened and the LCM is accumulated pairwise with [`checked_lcm`].
+/// - Time complexity: O(K * N<sup>2</sup>) where:
+///     - N - bits count in the biggest number.
+///     - K - number's count
+pub fn lcm_many_wide(elems: &[u64]) -> Option<u128> {
+    if elems.is_empty() || elems.contains(&0) {
+        return Some(0);
+    }
+
+    elems
+        .iter()
+        .try_fold(1, |acc, e| checked_lcm(acc, *e as u128))
+}
+
+/// Finds an LCM (Least Common Multiple) for a pair of numbers at compile time.
+/// Same as [`lcm`], but can be used in `const` contexts.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::const_lcm;
+///
+/// const RES: u64 = const_lcm(42, 144);
+/// let table = [0u8; const_lcm(4, 6) as usize];
+///
+/// assert_eq!(1008, RES);
+/// assert_eq!(12, table.len());
+/// ```
+/// ## Corner case
+/// LCM of zero and any number equals 0.
+/// ```
+/// use ads_rs::prelude::v1::math::const_lcm;
+///
+/// const RES: u64 = const_lcm(0, 42);
+///
+/// assert_eq!(0, RES);
+/// ```
+/// # Panics
+/// Panics if the LCM doesn't fit into `u64`, which is a compile error in `const` contexts.
+/// ```compile_fail
+/// use ads_rs::prelude::v1::math::const_lcm;
+///
+/// const RES: u64 = const_lcm(u64::MAX, u64::MAX - 1);
+///
+/// assert_eq!(0, RES);
+/// ```
+/// # Implementation details
+/// - Stein's algorithm used (from [`const_gcd`]).
+/// - Time complexity: O(N<sup>2</sup>) where N - number of bits in the biggest number.
+pub const fn const_lcm(lhs: u64, rhs: u64) -> u64 {
+    if lhs == 0 || rhs == 0 {
+        return 0;
+    }
+
+    match (lhs / const_gcd(lhs, rhs)).checked_mul(rhs) {
+        Some(res) => res,
+        None => panic!("LCM overflows u64"),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -92,9 +252,9 @@ mod tests {
             // Single element
             (vec![223], 223),
             // Relative prime numbers
-            (vec![1, 2, 3, 4, 5], 120),
+            (vec![1, 2, 3, 4, 5], 60),
             // Regular case
-            (vec![8, 24, 156, 36], 269568),
+            (vec![8, 24, 156, 36], 936),
             // All zeros
             (vec![0, 0, 0, 0], 0),
         ];
@@ -107,4 +267,61 @@ mod tests {
             assert_eq!(test_suits[i].1, result[i]);
         }
     }
+
+    #[test]
+    fn checked_lcm_many_works() {
+        // arrange
+        let test_suits = [
+            // Result fits exactly
+            (vec![1 << 32, (1 << 32) - 1], Some(u64::MAX - (1 << 32) + 1)),
+            // Product overflows, LCM doesn't
+            (vec![1 << 40, 1 << 50, 3 << 50], Some(3 << 50)),
+            // Overflow
+            (vec![1 << 40, 3, 5, 7, 11, 13, 17, 19, 23, 29], None),
+            // Zero with overflowing elements
+            (vec![u64::MAX, u64::MAX - 1, 0], Some(0)),
+        ];
+
+        // act
+        let result: Vec<Option<u64>> = test_suits.iter().map(|t| checked_lcm_many(&t.0)).collect();
+
+        // assert
+        for i in 0..test_suits.len() {
+            assert_eq!(test_suits[i].1, result[i]);
+        }
+    }
+
+    #[test]
+    fn const_lcm_matches_runtime() {
+        let values = [0, 1, 2, 3, 12, 18, 617, 2048, 1 << 32, (1 << 32) - 1];
+
+        for lhs in values {
+            for rhs in values {
+                assert_eq!(lcm(lhs, rhs), const_lcm(lhs, rhs));
+            }
+        }
+    }
+
+    #[test]
+    fn checked_lcm_signed_works() {
+        // arrange
+        let test_suits = [
+            // Negative numbers
+            (-4i8, -6i8, Some(12i8)),
+            // Fits exactly
+            (127, -1, Some(127)),
+            // Doesn't fit into signed type
+            (-128, 1, None),
+            // Zero
+            (-128, 0, Some(0)),
+        ];
+
+        // act
+        let result: Vec<Option<i8>> = test_suits.iter().map(|t| checked_lcm(t.0, t.1)).collect();
+
+        // assert
+        for i in 0..test_suits.len() {
+            assert_eq!(test_suits[i].2, result[i]);
+        }
+    }
 }
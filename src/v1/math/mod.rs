@@ -1,5 +1,47 @@
+mod big_int;
+mod big_uint;
+mod combinatorics;
+mod continued_fraction;
+mod crt;
+mod diophantine;
+mod discrete_log;
+mod euclidean_domain;
+mod factorize;
+mod gaussian_int;
 mod gcd;
+mod integer;
+mod iroot;
+mod lattice;
 mod lcm;
+mod mod_int;
+mod modular;
+mod multiplicative;
+pub mod poly;
+mod prime;
+mod primitive_root;
+mod ratio;
+pub mod sieve;
+mod sqrt_mod;
 
+pub use big_int::*;
+pub use big_uint::*;
+pub use combinatorics::*;
+pub use continued_fraction::*;
+pub use crt::*;
+pub use diophantine::*;
+pub use discrete_log::*;
+pub use euclidean_domain::*;
+pub use factorize::*;
+pub use gaussian_int::*;
 pub use gcd::*;
+pub use integer::*;
+pub use iroot::*;
+pub use lattice::*;
 pub use lcm::*;
+pub use mod_int::*;
+pub use modular::*;
+pub use multiplicative::*;
+pub use prime::*;
+pub use primitive_root::*;
+pub use ratio::*;
+pub use sqrt_mod::*;
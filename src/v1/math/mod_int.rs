@@ -0,0 +1,555 @@
+use crate::v1::math::mod_inv;
+use std::fmt::{Debug, Display, Formatter};
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// An integer modulo `M`, where `M` is a compile-time constant.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::ModInt;
+///
+/// type Mint = ModInt<1_000_000_007>;
+///
+/// let a = Mint::new(500_000_004);
+/// let b = Mint::new(3);
+///
+/// assert_eq!(1, (a * Mint::new(2)).value());
+/// assert_eq!(500_000_003, (a - Mint::new(1)).value());
+/// assert_eq!(1_000_000_004, (-b).value());
+/// assert_eq!(Some(Mint::new(333_333_336)), b.inv());
+/// assert_eq!(Mint::new(1), b / b);
+/// assert_eq!(500_000_004, Mint::new(2).pow(1_000_000_005).value());
+/// ```
+/// ## Corner cases
+/// - The modulus doesn't have to be prime, elements which aren't coprime with it have no inverse.
+/// - Modulo 1 every value equals 0.
+/// ```
+/// use ads_rs::prelude::v1::math::ModInt;
+///
+/// assert_eq!(None, ModInt::<12>::new(8).inv());
+/// assert_eq!(Some(ModInt::<12>::new(5)), ModInt::<12>::new(5).inv());
+/// assert_eq!(0, ModInt::<1>::new(5).pow(0).value());
+/// ```
+/// A zero modulus is rejected at compile time.
+/// ```compile_fail
+/// use ads_rs::prelude::v1::math::ModInt;
+///
+/// let _ = ModInt::<0>::new(5);
+/// ```
+/// # Panics
+/// Division panics if the divisor isn't invertible.
+/// # Implementation details
+/// - Reduction constants are computed at compile time (see [`Modulus`]).
+/// - Odd moduli use Montgomery multiplication: values are stored multiplied by 2<sup>64</sup>.
+/// - Even moduli use Barrett reduction of the 128-bit product.
+/// - The inverse is found with Extended Euclid's algorithm (from [`mod_inv`]), not Fermat's little theorem,
+///   so it works for composite moduli.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ModInt<const M: u64> {
+    repr: u64,
+}
+
+impl<const M: u64> ModInt<M> {
+    const MODULUS: &'static Modulus = &Modulus::new(M);
+
+    /// Creates a value equal to `value mod M`.
+    pub fn new(value: u64) -> Self {
+        Self {
+            repr: Self::MODULUS.encode(value),
+        }
+    }
+
+    /// Returns the modulus `M`.
+    pub fn modulus() -> u64 {
+        M
+    }
+
+    /// Returns the value in `[0, M)`.
+    pub fn value(self) -> u64 {
+        Self::MODULUS.decode(self.repr)
+    }
+
+    /// Raises the value to the power of `exp`, 0<sup>0</sup> equals 1.
+    pub fn pow(self, exp: u64) -> Self {
+        Self {
+            repr: Self::MODULUS.pow(self.repr, exp),
+        }
+    }
+
+    /// Finds the multiplicative inverse, `None` if the value isn't coprime with `M`.
+    pub fn inv(self) -> Option<Self> {
+        mod_inv(self.value(), M).map(Self::new)
+    }
+
+    fn modulus_ref(&self) -> &Modulus {
+        Self::MODULUS
+    }
+
+    fn with_repr(self, repr: u64) -> Self {
+        Self { repr }
+    }
+
+    fn assert_same_modulus(&self, _: &Self) {}
+}
+
+impl<const M: u64> From<u64> for ModInt<M> {
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const M: u64> Debug for ModInt<M> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (mod {})", self.value(), M)
+    }
+}
+
+/// An integer modulo a [`Modulus`] chosen at runtime.
+/// Values share the modulus by reference, arithmetic on values with different moduli panics.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::{DynModInt, Modulus};
+///
+/// let m = Modulus::new(998_244_353);
+/// let a = DynModInt::new(3, &m);
+///
+/// assert_eq!(998_244_353, a.modulus());
+/// assert_eq!(1, a.pow(998_244_352).value());
+/// assert_eq!(Some(DynModInt::new(332_748_118, &m)), a.inv());
+/// assert_eq!(6, (a + a).value());
+/// ```
+/// ## Corner case
+/// Values with different moduli can't be mixed.
+/// ```should_panic
+/// use ads_rs::prelude::v1::math::{DynModInt, Modulus};
+///
+/// let (m0, m1) = (Modulus::new(7), Modulus::new(11));
+///
+/// let _ = DynModInt::new(3, &m0) + DynModInt::new(3, &m1);
+/// ```
+/// # Panics
+/// Division panics if the divisor isn't invertible.
+/// # Implementation details
+/// The same as for [`ModInt`], but reduction constants are computed once by [`Modulus::new`].
+#[derive(Clone, Copy)]
+pub struct DynModInt<'a> {
+    repr: u64,
+    modulus: &'a Modulus,
+}
+
+impl<'a> DynModInt<'a> {
+    /// Creates a value equal to `value mod modulus`.
+    pub fn new(value: u64, modulus: &'a Modulus) -> Self {
+        Self {
+            repr: modulus.encode(value),
+            modulus,
+        }
+    }
+
+    /// Returns the modulus.
+    pub fn modulus(&self) -> u64 {
+        self.modulus.value
+    }
+
+    /// Returns the value in `[0, modulus)`.
+    pub fn value(self) -> u64 {
+        self.modulus.decode(self.repr)
+    }
+
+    /// Raises the value to the power of `exp`, 0<sup>0</sup> equals 1.
+    pub fn pow(self, exp: u64) -> Self {
+        self.with_repr(self.modulus.pow(self.repr, exp))
+    }
+
+    /// Finds the multiplicative inverse, `None` if the value isn't coprime with the modulus.
+    pub fn inv(self) -> Option<Self> {
+        mod_inv(self.value(), self.modulus.value).map(|value| Self::new(value, self.modulus))
+    }
+
+    fn modulus_ref(&self) -> &Modulus {
+        self.modulus
+    }
+
+    fn with_repr(self, repr: u64) -> Self {
+        Self {
+            repr,
+            modulus: self.modulus,
+        }
+    }
+
+    fn assert_same_modulus(&self, rhs: &Self) {
+        assert_eq!(
+            self.modulus.value, rhs.modulus.value,
+            "values have different moduli"
+        );
+    }
+}
+
+impl PartialEq for DynModInt<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.repr == other.repr && self.modulus.value == other.modulus.value
+    }
+}
+
+impl Eq for DynModInt<'_> {}
+
+impl Debug for DynModInt<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (mod {})", self.value(), self.modulus.value)
+    }
+}
+
+/// A modulus with precomputed reduction constants for [`ModInt`] and [`DynModInt`].
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::Modulus;
+///
+/// const M: Modulus = Modulus::new(1 << 61);
+///
+/// assert_eq!(1 << 61, M.value());
+/// ```
+/// # Panics
+/// Panics if the modulus equals 0.
+/// # Implementation details
+/// - For odd moduli M<sup>-1</sup> mod 2<sup>64</sup> is found with Newton's iteration
+///   and 2<sup>128</sup> mod M is kept to convert values into Montgomery form.
+/// - For even moduli ⌊(2<sup>128</sup> - 1) / M⌋ is kept for Barrett reduction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Modulus {
+    value: u64,
+    reduction: Reduction,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Reduction {
+    Montgomery { inv: u64, r2: u64 },
+    Barrett { factor: u128 },
+}
+
+impl Modulus {
+    /// Precomputes reduction constants for a modulus.
+    pub const fn new(value: u64) -> Self {
+        assert!(value != 0, "modulus equals 0");
+
+        let reduction = if value % 2 == 1 {
+            // every step doubles the count of correct low bits, value * value = 1 mod 8
+            let mut inv = value;
+            let mut i = 0;
+            while i < 5 {
+                inv = inv.wrapping_mul(2u64.wrapping_sub(value.wrapping_mul(inv)));
+                i += 1;
+            }
+
+            let r2 = ((u128::MAX % value as u128 + 1) % value as u128) as u64;
+            Reduction::Montgomery { inv, r2 }
+        } else {
+            Reduction::Barrett {
+                factor: u128::MAX / value as u128,
+            }
+        };
+
+        Self { value, reduction }
+    }
+
+    /// Returns the modulus.
+    pub const fn value(&self) -> u64 {
+        self.value
+    }
+
+    #[inline]
+    fn encode(&self, value: u64) -> u64 {
+        match self.reduction {
+            Reduction::Montgomery { r2, .. } => self.mul(value % self.value, r2),
+            Reduction::Barrett { .. } => value % self.value,
+        }
+    }
+
+    #[inline]
+    fn decode(&self, repr: u64) -> u64 {
+        match self.reduction {
+            Reduction::Montgomery { inv, .. } => self.redc(repr as u128, inv),
+            Reduction::Barrett { .. } => repr,
+        }
+    }
+
+    #[inline]
+    fn add(&self, lhs: u64, rhs: u64) -> u64 {
+        let (sum, overflow) = lhs.overflowing_add(rhs);
+
+        if overflow || sum >= self.value {
+            sum.wrapping_sub(self.value)
+        } else {
+            sum
+        }
+    }
+
+    #[inline]
+    fn sub(&self, lhs: u64, rhs: u64) -> u64 {
+        if lhs >= rhs {
+            lhs - rhs
+        } else {
+            lhs.wrapping_sub(rhs).wrapping_add(self.value)
+        }
+    }
+
+    #[inline]
+    fn mul(&self, lhs: u64, rhs: u64) -> u64 {
+        let product = lhs as u128 * rhs as u128;
+
+        match self.reduction {
+            Reduction::Montgomery { inv, .. } => self.redc(product, inv),
+            Reduction::Barrett { factor } => {
+                let mut rem = product - mul_high(product, factor) * self.value as u128;
+                while rem >= self.value as u128 {
+                    rem -= self.value as u128;
+                }
+
+                rem as u64
+            }
+        }
+    }
+
+    fn pow(&self, mut base: u64, mut exp: u64) -> u64 {
+        let mut res = self.encode(1);
+
+        while exp > 0 {
+            if exp & 1 == 1 {
+                res = self.mul(res, base);
+            }
+            base = self.mul(base, base);
+            exp >>= 1;
+        }
+
+        res
+    }
+
+    /// Montgomery reduction: returns `t / 2^64 mod M` for `t < M * 2^64`.
+    #[inline]
+    fn redc(&self, t: u128, inv: u64) -> u64 {
+        // t - m * M is divisible by 2^64, so it equals the difference of the high halves
+        let m = (t as u64).wrapping_mul(inv);
+        let (t_high, mm_high) = (
+            (t >> 64) as u64,
+            ((m as u128 * self.value as u128) >> 64) as u64,
+        );
+
+        if t_high >= mm_high {
+            t_high - mm_high
+        } else {
+            t_high + (self.value - mm_high)
+        }
+    }
+}
+
+/// Returns the high 128 bits of the 256-bit product.
+#[inline]
+fn mul_high(lhs: u128, rhs: u128) -> u128 {
+    let (lhs_low, lhs_high) = (lhs as u64 as u128, lhs >> 64);
+    let (rhs_low, rhs_high) = (rhs as u64 as u128, rhs >> 64);
+
+    let low_low = lhs_low * rhs_low;
+    let high_low = lhs_high * rhs_low;
+    let low_high = lhs_low * rhs_high;
+    let carry = ((low_low >> 64) + (high_low as u64 as u128) + (low_high as u64 as u128)) >> 64;
+
+    lhs_high * rhs_high + (high_low >> 64) + (low_high >> 64) + carry
+}
+
+macro_rules! impl_mod_int_ops {
+    ($t:ty; $($generics:tt)*) => {
+        impl<$($generics)*> Add for $t {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                self.assert_same_modulus(&rhs);
+                self.with_repr(self.modulus_ref().add(self.repr, rhs.repr))
+            }
+        }
+
+        impl<$($generics)*> Sub for $t {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                self.assert_same_modulus(&rhs);
+                self.with_repr(self.modulus_ref().sub(self.repr, rhs.repr))
+            }
+        }
+
+        impl<$($generics)*> Mul for $t {
+            type Output = Self;
+
+            fn mul(self, rhs: Self) -> Self::Output {
+                self.assert_same_modulus(&rhs);
+                self.with_repr(self.modulus_ref().mul(self.repr, rhs.repr))
+            }
+        }
+
+        impl<$($generics)*> Div for $t {
+            type Output = Self;
+
+            fn div(self, rhs: Self) -> Self::Output {
+                self.assert_same_modulus(&rhs);
+                let inv = rhs.inv().expect("divisor isn't invertible");
+                self.with_repr(self.modulus_ref().mul(self.repr, inv.repr))
+            }
+        }
+
+        impl<$($generics)*> Neg for $t {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                self.with_repr(self.modulus_ref().sub(0, self.repr))
+            }
+        }
+
+        impl<$($generics)*> AddAssign for $t {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl<$($generics)*> SubAssign for $t {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl<$($generics)*> MulAssign for $t {
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl<$($generics)*> DivAssign for $t {
+            fn div_assign(&mut self, rhs: Self) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl<$($generics)*> Display for $t {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.value())
+            }
+        }
+    };
+}
+
+impl_mod_int_ops!(ModInt<M>; const M: u64);
+impl_mod_int_ops!(DynModInt<'a>; 'a);
+
+impl<const M: u64> Sum for ModInt<M> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |acc, x| acc + x)
+    }
+}
+
+impl<const M: u64> Product for ModInt<M> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(1), |acc, x| acc * x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v1::math::prime::SplitMix64;
+    use crate::v1::math::{mod_add, mod_mul, mod_pow, mod_sub};
+
+    const MODULI: [u64; 12] = [
+        1,
+        2,
+        3,
+        12,
+        998_244_353,
+        1_000_000_007,
+        1 << 32,
+        (1 << 61) - 1,
+        1 << 63,
+        u64::MAX - 1,
+        u64::MAX - 58,
+        u64::MAX,
+    ];
+
+    #[test]
+    fn dyn_mod_int_matches_modular() {
+        let mut rng = SplitMix64(42);
+
+        for m in MODULI {
+            let modulus = Modulus::new(m);
+
+            for _ in 0..2000 {
+                let (a, b, e) = (rng.next(), rng.next(), rng.next() % 1000);
+                let (x, y) = (DynModInt::new(a, &modulus), DynModInt::new(b, &modulus));
+                let (a, b) = (a % m, b % m);
+
+                assert_eq!(a, x.value());
+                assert_eq!(mod_add(a, b, m), (x + y).value());
+                assert_eq!(mod_sub(a, b, m), (x - y).value());
+                assert_eq!(mod_sub(0, a, m), (-x).value());
+                assert_eq!(mod_mul(a, b, m), (x * y).value());
+                assert_eq!(mod_pow(a, e, m), x.pow(e).value());
+                assert_eq!(mod_inv(a, m), x.inv().map(|x| x.value()));
+            }
+        }
+    }
+
+    #[test]
+    fn mod_int_matches_dyn_mod_int() {
+        fn check<const M: u64>() {
+            let modulus = Modulus::new(M);
+            let mut rng = SplitMix64(M);
+
+            for _ in 0..2000 {
+                let (a, b) = (rng.next(), rng.next());
+                let (x, y) = (ModInt::<M>::new(a), ModInt::<M>::new(b));
+                let (dx, dy) = (DynModInt::new(a, &modulus), DynModInt::new(b, &modulus));
+
+                assert_eq!((dx * dy).value(), (x * y).value());
+                assert_eq!((dx + dy * dx).value(), (x + y * x).value());
+                if let Some(inv) = y.inv() {
+                    assert_eq!(x, x / y * y);
+                    assert_eq!(ModInt::new(1), inv * y);
+                }
+            }
+        }
+
+        check::<1>();
+        check::<2>();
+        check::<12>();
+        check::<998_244_353>();
+        check::<{ 1 << 63 }>();
+        check::<{ u64::MAX - 58 }>();
+        check::<{ u64::MAX }>();
+    }
+
+    #[test]
+    fn assign_ops_and_iterators_work() {
+        type Mint = ModInt<1_000_000_007>;
+        const M: u64 = 1_000_000_007;
+
+        // arrange
+        let values: Vec<Mint> = (1..=20).map(Mint::new).collect();
+        let expected = (1..=20).fold(0, |acc, v| {
+            let acc = mod_sub(mod_mul(mod_add(acc, v, M), v, M), 1, M);
+            mod_mul(acc, mod_inv(v, M).unwrap(), M)
+        });
+
+        // act
+        let mut acc = Mint::new(0);
+        for &v in &values {
+            acc += v;
+            acc *= v;
+            acc -= Mint::new(1);
+            acc /= v;
+        }
+
+        // assert
+        assert_eq!(expected, acc.value());
+        assert_eq!(Mint::new(210), values.iter().copied().sum());
+        assert_eq!(Mint::new(146_326_063), values.iter().copied().product());
+        assert_eq!(
+            "146326063",
+            values.into_iter().product::<Mint>().to_string()
+        );
+    }
+}
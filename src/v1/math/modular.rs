@@ -0,0 +1,290 @@
+use crate::v1::math::extended_gcd_wide;
+
+/// Finds `(lhs + rhs) mod m` without overflow.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::mod_add;
+///
+/// let res0 = mod_add(5, 8, 7);
+/// let res1 = mod_add(u64::MAX - 1, u64::MAX - 1, u64::MAX);
+///
+/// assert_eq!(6, res0);
+/// assert_eq!(u64::MAX - 2, res1);
+/// ```
+/// # Panics
+/// Panics if `m == 0`.
+/// # Implementation details
+/// - Arguments are reduced first, then the sum is compared against `m` instead of being computed.
+/// - Time complexity is O(1)
+pub fn mod_add(lhs: u64, rhs: u64, m: u64) -> u64 {
+    let (lhs, rhs) = (lhs % m, rhs % m);
+
+    if lhs >= m - rhs {
+        lhs - (m - rhs)
+    } else {
+        lhs + rhs
+    }
+}
+
+/// Finds `(lhs - rhs) mod m` without overflow, the result is always in `[0, m)`.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::mod_sub;
+///
+/// let res0 = mod_sub(8, 5, 7);
+/// let res1 = mod_sub(5, 8, 7);
+///
+/// assert_eq!(3, res0);
+/// assert_eq!(4, res1);
+/// ```
+/// # Panics
+/// Panics if `m == 0`.
+/// # Implementation details
+/// - Time complexity is O(1)
+pub fn mod_sub(lhs: u64, rhs: u64, m: u64) -> u64 {
+    let (lhs, rhs) = (lhs % m, rhs % m);
+
+    if lhs >= rhs {
+        lhs - rhs
+    } else {
+        lhs + (m - rhs)
+    }
+}
+
+/// Finds `(lhs * rhs) mod m` without overflow.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::mod_mul;
+///
+/// let res0 = mod_mul(5, 8, 7);
+/// let res1 = mod_mul(u64::MAX - 1, u64::MAX - 1, u64::MAX);
+///
+/// assert_eq!(5, res0);
+/// assert_eq!(1, res1);
+/// ```
+/// # Panics
+/// Panics if `m == 0`.
+/// # Implementation details
+/// - The product is computed in `u128`.
+/// - Time complexity is O(1)
+#[inline]
+pub fn mod_mul(lhs: u64, rhs: u64, m: u64) -> u64 {
+    ((lhs as u128 * rhs as u128) % m as u128) as u64
+}
+
+/// Finds `base`<sup>`exp`</sup>` mod m` without overflow.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::mod_pow;
+///
+/// let res0 = mod_pow(3, 4, 7);
+/// let res1 = mod_pow(2, 64, u64::MAX);
+///
+/// assert_eq!(4, res0);
+/// assert_eq!(1, res1);
+/// ```
+/// ## Corner cases
+/// - Any number (including 0) raised to the power of 0 equals `1 mod m`.
+/// ```
+/// use ads_rs::prelude::v1::math::mod_pow;
+///
+/// let res0 = mod_pow(0, 0, 7);
+/// let res1 = mod_pow(5, 0, 1);
+///
+/// assert_eq!(1, res0);
+/// assert_eq!(0, res1);
+/// ```
+/// # Panics
+/// Panics if `m == 0`.
+/// # Implementation details
+/// - Binary exponentiation with [`mod_mul`] is used.
+/// - Time complexity is O(log<sub>2</sub>(exp))
+pub fn mod_pow(base: u64, mut exp: u64, m: u64) -> u64 {
+    let (mut res, mut base) = (1 % m, base % m);
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            res = mod_mul(res, base, m);
+        }
+
+        base = mod_mul(base, base, m);
+        exp >>= 1;
+    }
+
+    res
+}
+
+/// Finds the modular multiplicative inverse `x` such that `(a * x) mod m = 1 mod m`.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::mod_inv;
+///
+/// let res0 = mod_inv(3, 7);
+/// let res1 = mod_inv(4, 6);
+/// let res2 = mod_inv(u64::MAX - 1, u64::MAX);
+///
+/// assert_eq!(Some(5), res0);
+/// assert_eq!(None, res1);
+/// assert_eq!(Some(u64::MAX - 1), res2);
+/// ```
+/// ## Corner cases
+/// - The inverse exists only if `gcd(a, m) == 1`, otherwise `None` is returned.
+/// - Every number is invertible modulo 1, and the inverse equals 0.
+/// ```
+/// use ads_rs::prelude::v1::math::mod_inv;
+///
+/// let res0 = mod_inv(0, 7);
+/// let res1 = mod_inv(0, 1);
+///
+/// assert_eq!(None, res0);
+/// assert_eq!(Some(0), res1);
+/// ```
+/// # Panics
+/// Panics if `m == 0`.
+/// # Implementation details
+/// - Extended Euclid's algorithm used (from [`extended_gcd_wide`]).
+/// - Time complexity is O(log<sub>2</sub>(m))
+pub fn mod_inv(a: u64, m: u64) -> Option<u64> {
+    let (gcd, x, _) = extended_gcd_wide(a % m, m);
+
+    if gcd != 1 {
+        return None;
+    }
+
+    Some(x.rem_euclid(m as i128) as u64)
+}
+
+/// Finds `(lhs * rhs) mod m` for `u128` numbers without overflow.
+/// Both `lhs` and `rhs` are expected to be reduced modulo `m` already.
+pub(crate) fn mod_mul_u128(lhs: u128, rhs: u128, m: u128) -> u128 {
+    if let (Ok(lhs), Ok(rhs), Ok(m)) = (u64::try_from(lhs), u64::try_from(rhs), u64::try_from(m)) {
+        return mod_mul(lhs, rhs, m) as u128;
+    }
+
+    // binary multiplication with overflow-free modular doubling
+    let mut res = 0;
+    for bit in (0..u128::BITS - rhs.leading_zeros()).rev() {
+        res = mod_add_u128(res, res, m);
+        if (rhs >> bit) & 1 == 1 {
+            res = mod_add_u128(res, lhs, m);
+        }
+    }
+
+    res
+}
+
+/// Finds `base`<sup>`exp`</sup>` mod m` for `u128` numbers without overflow.
+pub(crate) fn mod_pow_u128(base: u128, mut exp: u128, m: u128) -> u128 {
+    let (mut res, mut base) = (1 % m, base % m);
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            res = mod_mul_u128(res, base, m);
+        }
+
+        base = mod_mul_u128(base, base, m);
+        exp >>= 1;
+    }
+
+    res
+}
+
+/// Finds `(lhs + rhs) mod m` for `u128` numbers reduced modulo `m`.
+fn mod_add_u128(lhs: u128, rhs: u128, m: u128) -> u128 {
+    if lhs >= m - rhs {
+        lhs - (m - rhs)
+    } else {
+        lhs + rhs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v1::math::gcd;
+
+    #[test]
+    fn mod_arithmetic_matches_brute_force() {
+        for m in 1..=40u64 {
+            for a in 0..2 * m {
+                for b in 0..2 * m {
+                    assert_eq!((a + b) % m, mod_add(a, b, m));
+                    assert_eq!((a + 2 * m - b) % m, mod_sub(a, b, m));
+                    assert_eq!((a * b) % m, mod_mul(a, b, m));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn mod_pow_matches_brute_force() {
+        for m in 1..=40u64 {
+            for base in 0..m {
+                let mut expected = 1 % m;
+                for exp in 0..50 {
+                    assert_eq!(expected, mod_pow(base, exp, m));
+                    expected = expected * base % m;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn mod_inv_matches_brute_force() {
+        for m in 1..=200u64 {
+            for a in 0..2 * m {
+                let expected = (0..m).find(|x| a * x % m == 1 % m);
+                assert_eq!(expected, mod_inv(a, m));
+                assert_eq!(expected.is_some(), gcd(a, m) == 1);
+            }
+        }
+    }
+
+    #[test]
+    fn mod_mul_u128_works() {
+        // arrange
+        let m = u128::MAX - 158; // the largest 128-bit prime
+        let test_suits = [
+            (0, m - 1, 0),
+            (1, m - 1, m - 1),
+            (m - 1, m - 1, 1),
+            (1 << 127, 2, 159),
+            (
+                u64::MAX as u128,
+                u64::MAX as u128,
+                u64::MAX as u128 * u64::MAX as u128,
+            ),
+        ];
+
+        // act
+        let result: Vec<u128> = test_suits
+            .iter()
+            .map(|t| mod_mul_u128(t.0, t.1, m))
+            .collect();
+
+        // assert
+        for i in 0..test_suits.len() {
+            assert_eq!(test_suits[i].2, result[i]);
+        }
+        assert_eq!(1, mod_pow_u128(3, m - 1, m));
+    }
+
+    #[test]
+    fn mod_arithmetic_wide_works() {
+        // arrange
+        let m = 18_446_744_073_709_551_557; // the largest 64-bit prime
+        let test_suits = [1, 2, m - 1, m / 2, 12_345_678_901_234_567_890];
+
+        // act
+        let result: Vec<Option<u64>> = test_suits.iter().map(|t| mod_inv(*t, m)).collect();
+
+        // assert
+        for i in 0..test_suits.len() {
+            let inv = result[i].unwrap();
+            assert_eq!(1, mod_mul(test_suits[i], inv, m));
+            assert_eq!(inv, mod_pow(test_suits[i], m - 2, m));
+            assert_eq!(0, mod_add(test_suits[i], m - test_suits[i], m));
+            assert_eq!(0, mod_sub(test_suits[i], test_suits[i], m));
+        }
+    }
+}
@@ -0,0 +1,431 @@
+use crate::v1::math::factorize;
+use crate::v1::math::sieve::LinearSieve;
+use std::iter::FusedIterator;
+
+/// Finds Euler's totient function φ(n), the count of numbers in `[1, n]` coprime with `n`.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::euler_phi;
+///
+/// let res0 = euler_phi(36);
+/// let res1 = euler_phi(97);
+/// let res2 = euler_phi(1);
+///
+/// assert_eq!(12, res0);
+/// assert_eq!(96, res1);
+/// assert_eq!(1, res2);
+/// ```
+/// ## Corner case
+/// φ(0) equals 0.
+/// ```
+/// use ads_rs::prelude::v1::math::euler_phi;
+///
+/// assert_eq!(0, euler_phi(0));
+/// ```
+/// # Implementation details
+/// - φ(n) = n * Π(1 - 1 / p) over prime divisors `p`, the factorization is found with [`factorize`].
+/// - Time complexity is O(n<sup>1/4</sup>) expected.
+pub fn euler_phi(n: u64) -> u64 {
+    factorize(n).iter().fold(n, |acc, (p, _)| acc / p * (p - 1))
+}
+
+/// Finds the Möbius function μ(n): 0 if `n` has a squared prime divisor,
+/// otherwise `(-1)`<sup>`k`</sup> where `k` is the count of prime divisors.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::mobius;
+///
+/// let res0 = mobius(30);
+/// let res1 = mobius(12);
+/// let res2 = mobius(1);
+///
+/// assert_eq!(-1, res0);
+/// assert_eq!(0, res1);
+/// assert_eq!(1, res2);
+/// ```
+/// ## Corner case
+/// μ(0) equals 0.
+/// ```
+/// use ads_rs::prelude::v1::math::mobius;
+///
+/// assert_eq!(0, mobius(0));
+/// ```
+/// # Implementation details
+/// - The factorization is found with [`factorize`].
+/// - Time complexity is O(n<sup>1/4</sup>) expected.
+pub fn mobius(n: u64) -> i8 {
+    if n == 0 {
+        return 0;
+    }
+
+    let factors = factorize(n);
+    if factors.iter().any(|(_, e)| *e > 1) {
+        0
+    } else if factors.len().is_multiple_of(2) {
+        1
+    } else {
+        -1
+    }
+}
+
+/// Finds the count of divisors of `n`, also known as τ(n) or d(n).
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::divisor_count;
+///
+/// let res0 = divisor_count(36);
+/// let res1 = divisor_count(97);
+/// let res2 = divisor_count(1);
+///
+/// assert_eq!(9, res0);
+/// assert_eq!(2, res1);
+/// assert_eq!(1, res2);
+/// ```
+/// ## Corner case
+/// Every number divides 0, so the count isn't defined and 0 is returned.
+/// ```
+/// use ads_rs::prelude::v1::math::divisor_count;
+///
+/// assert_eq!(0, divisor_count(0));
+/// ```
+/// # Implementation details
+/// - d(n) = Π(e + 1) over prime powers `p`<sup>`e`</sup> of the factorization from [`factorize`].
+/// - Time complexity is O(n<sup>1/4</sup>) expected.
+pub fn divisor_count(n: u64) -> u64 {
+    if n == 0 {
+        return 0;
+    }
+
+    factorize(n).iter().map(|(_, e)| *e as u64 + 1).product()
+}
+
+/// Finds the sum of divisors of `n`, also known as σ(n).
+/// The result is `u128`, because σ(n) may exceed `u64::MAX` for `n` close to it.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::divisor_sum;
+///
+/// let res0 = divisor_sum(36);
+/// let res1 = divisor_sum(97);
+/// let res2 = divisor_sum(1);
+///
+/// assert_eq!(91, res0);
+/// assert_eq!(98, res1);
+/// assert_eq!(1, res2);
+/// ```
+/// ## Corner case
+/// Every number divides 0, so the sum isn't defined and 0 is returned.
+/// ```
+/// use ads_rs::prelude::v1::math::divisor_sum;
+///
+/// assert_eq!(0, divisor_sum(0));
+/// ```
+/// # Implementation details
+/// - σ(n) = Π(1 + p + ... + p<sup>e</sup>) over prime powers of the factorization from [`factorize`].
+/// - Time complexity is O(n<sup>1/4</sup>) expected.
+pub fn divisor_sum(n: u64) -> u128 {
+    if n == 0 {
+        return 0;
+    }
+
+    factorize(n)
+        .iter()
+        .map(|&(p, e)| (0..e).fold(1u128, |acc, _| acc * p as u128 + 1))
+        .product()
+}
+
+/// Returns an iterator over all divisors of `n`, see [`Divisors`].
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::divisors;
+///
+/// let mut res: Vec<u64> = divisors(36).collect();
+/// res.sort();
+///
+/// assert_eq!(vec![1, 2, 3, 4, 6, 9, 12, 18, 36], res);
+/// assert_eq!(1, divisors(1).count());
+/// assert_eq!(0, divisors(0).count());
+/// ```
+pub fn divisors(n: u64) -> Divisors {
+    Divisors::new(n)
+}
+
+/// Lazy iterator over all divisors of a number.
+/// Divisors aren't sorted: the exponent of the smallest prime changes most frequently.
+/// ## Corner case
+/// Every number divides 0, so the iterator over divisors of 0 is empty.
+/// # Implementation details
+/// - The factorization is found with [`factorize`], then exponents are enumerated like an odometer.
+/// - Every step takes O(1) amortized time.
+#[derive(Debug, Clone)]
+pub struct Divisors {
+    factors: Vec<(u64, u32)>,
+    exps: Vec<u32>,
+    current: u64,
+    done: bool,
+}
+
+impl Divisors {
+    fn new(n: u64) -> Self {
+        let factors = factorize(n);
+        let exps = vec![0; factors.len()];
+
+        Self {
+            factors,
+            exps,
+            current: 1,
+            done: n == 0,
+        }
+    }
+}
+
+impl Iterator for Divisors {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let res = self.current;
+
+        // increment the lowest exponent which hasn't reached its maximum, resetting the lower ones
+        self.done = true;
+        for (i, &(p, e)) in self.factors.iter().enumerate() {
+            if self.exps[i] < e {
+                self.exps[i] += 1;
+                self.current *= p;
+                self.done = false;
+                break;
+            }
+
+            self.current /= p.pow(e);
+            self.exps[i] = 0;
+        }
+
+        Some(res)
+    }
+}
+
+impl FusedIterator for Divisors {}
+
+/// Finds φ(k) for every `k` in `[0, n]`, see [`euler_phi`].
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::euler_phi_table;
+///
+/// assert_eq!(vec![0, 1, 1, 2, 2, 4, 2, 6, 4, 6, 4], euler_phi_table(10));
+/// ```
+/// # Panics
+/// Panics if `n > u32::MAX`.
+/// # Implementation details
+/// - Linear sieve is used (from [`LinearSieve`]): for `k = p * m` with the smallest prime `p`,
+///   φ(k) = φ(m) * p if `p` divides `m`, and φ(m) * (p - 1) otherwise.
+/// - Time and memory complexity is O(n).
+pub fn euler_phi_table(n: usize) -> Vec<u64> {
+    let sieve = LinearSieve::new(n);
+    let mut phi = vec![0u64; n + 1];
+
+    if n >= 1 {
+        phi[1] = 1;
+    }
+
+    for k in 2..=n {
+        let (p, m) = split_smallest_prime(&sieve, k);
+        phi[k] = if m.is_multiple_of(p) {
+            phi[m] * p as u64
+        } else {
+            phi[m] * (p as u64 - 1)
+        };
+    }
+
+    phi
+}
+
+/// Finds μ(k) for every `k` in `[0, n]`, see [`mobius`].
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::mobius_table;
+///
+/// assert_eq!(vec![0, 1, -1, -1, 0, -1, 1, -1, 0, 0, 1], mobius_table(10));
+/// ```
+/// # Panics
+/// Panics if `n > u32::MAX`.
+/// # Implementation details
+/// - Linear sieve is used (from [`LinearSieve`]): for `k = p * m` with the smallest prime `p`,
+///   μ(k) = 0 if `p` divides `m`, and -μ(m) otherwise.
+/// - Time and memory complexity is O(n).
+pub fn mobius_table(n: usize) -> Vec<i8> {
+    let sieve = LinearSieve::new(n);
+    let mut mu = vec![0i8; n + 1];
+
+    if n >= 1 {
+        mu[1] = 1;
+    }
+
+    for k in 2..=n {
+        let (p, m) = split_smallest_prime(&sieve, k);
+        mu[k] = if m.is_multiple_of(p) { 0 } else { -mu[m] };
+    }
+
+    mu
+}
+
+/// Finds d(k) for every `k` in `[0, n]`, see [`divisor_count`].
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::divisor_count_table;
+///
+/// assert_eq!(vec![0, 1, 2, 2, 3, 2, 4, 2, 4, 3, 4], divisor_count_table(10));
+/// ```
+/// # Panics
+/// Panics if `n > u32::MAX`.
+/// # Implementation details
+/// - Linear sieve is used (from [`LinearSieve`]), the exponent of the smallest prime is tracked
+///   for every number to update its factor `e + 1`.
+/// - Time and memory complexity is O(n).
+pub fn divisor_count_table(n: usize) -> Vec<u64> {
+    let sieve = LinearSieve::new(n);
+    let mut d = vec![0u64; n + 1];
+    // exponent of the smallest prime
+    let mut exp = vec![0u64; n + 1];
+
+    if n >= 1 {
+        d[1] = 1;
+    }
+
+    for k in 2..=n {
+        let (p, m) = split_smallest_prime(&sieve, k);
+        if m.is_multiple_of(p) {
+            exp[k] = exp[m] + 1;
+            d[k] = d[m] / (exp[m] + 1) * (exp[k] + 1);
+        } else {
+            exp[k] = 1;
+            d[k] = d[m] * 2;
+        }
+    }
+
+    d
+}
+
+/// Finds σ(k) for every `k` in `[0, n]`, see [`divisor_sum`].
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::divisor_sum_table;
+///
+/// assert_eq!(vec![0, 1, 3, 4, 7, 6, 12, 8, 15, 13, 18], divisor_sum_table(10));
+/// ```
+/// # Panics
+/// Panics if `n > u32::MAX`.
+/// # Implementation details
+/// - Linear sieve is used (from [`LinearSieve`]): for `k = p`<sup>`e`</sup>` * r` with the smallest prime `p`,
+///   σ(k) = σ(p<sup>e</sup>) * σ(r), where both `r` and σ(p<sup>e</sup>) are tracked for every number.
+/// - Time and memory complexity is O(n).
+pub fn divisor_sum_table(n: usize) -> Vec<u64> {
+    let sieve = LinearSieve::new(n);
+    let mut sigma = vec![0u64; n + 1];
+    // number without the smallest prime power, and σ of that power
+    let mut rest = vec![0usize; n + 1];
+    let mut prime_power_sigma = vec![0u64; n + 1];
+
+    if n >= 1 {
+        sigma[1] = 1;
+    }
+
+    for k in 2..=n {
+        let (p, m) = split_smallest_prime(&sieve, k);
+        if m.is_multiple_of(p) {
+            rest[k] = rest[m];
+            prime_power_sigma[k] = prime_power_sigma[m] * p as u64 + 1;
+        } else {
+            rest[k] = m;
+            prime_power_sigma[k] = p as u64 + 1;
+        }
+
+        sigma[k] = sigma[rest[k]] * prime_power_sigma[k];
+    }
+
+    sigma
+}
+
+/// Splits `k >= 2` into its smallest prime `p` and `k / p`.
+fn split_smallest_prime(sieve: &LinearSieve, k: usize) -> (usize, usize) {
+    let p = sieve
+        .smallest_prime_factor(k)
+        .expect("numbers from 2 have a prime factor");
+
+    (p, k / p)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v1::math::gcd;
+
+    const N: usize = 2_000;
+
+    #[test]
+    fn functions_match_brute_force() {
+        for n in 1..=N as u64 {
+            let divs: Vec<u64> = (1..=n).filter(|d| n % d == 0).collect();
+            let mut actual: Vec<u64> = divisors(n).collect();
+            actual.sort();
+
+            assert_eq!(divs, actual);
+            assert_eq!(divs.len() as u64, divisor_count(n));
+            assert_eq!(divs.iter().sum::<u64>() as u128, divisor_sum(n));
+            assert_eq!(
+                (1..=n).filter(|k| gcd(*k, n) == 1).count() as u64,
+                euler_phi(n)
+            );
+
+            // Σ μ(d) over divisors equals 1 for n = 1 and 0 otherwise
+            let mobius_sum: i64 = divs.iter().map(|d| mobius(*d) as i64).sum();
+            assert_eq!((n == 1) as i64, mobius_sum);
+        }
+    }
+
+    #[test]
+    fn tables_match_functions() {
+        let phi = euler_phi_table(N);
+        let mu = mobius_table(N);
+        let d = divisor_count_table(N);
+        let sigma = divisor_sum_table(N);
+
+        for n in 0..=N {
+            assert_eq!(euler_phi(n as u64), phi[n]);
+            assert_eq!(mobius(n as u64), mu[n]);
+            assert_eq!(divisor_count(n as u64), d[n]);
+            assert_eq!(divisor_sum(n as u64), sigma[n] as u128);
+        }
+    }
+
+    #[test]
+    fn tables_corner_cases_work() {
+        assert_eq!(vec![0], euler_phi_table(0));
+        assert_eq!(vec![0, 1], mobius_table(1));
+        assert_eq!(vec![0, 1], divisor_count_table(1));
+        assert_eq!(vec![0], divisor_sum_table(0));
+    }
+
+    #[test]
+    fn functions_wide_work() {
+        // 2^64 - 1 = 3 * 5 * 17 * 257 * 641 * 65537 * 6700417
+        let n = u64::MAX;
+
+        assert_eq!(
+            18_446_744_073_709_551_615 / 3 * 2 / 5 * 4 / 17 * 16 / 257 * 256 / 641 * 640 / 65537
+                * 65536
+                / 6_700_417
+                * 6_700_416,
+            euler_phi(n)
+        );
+        assert_eq!(-1, mobius(n));
+        assert_eq!(128, divisor_count(n));
+        assert_eq!(128, divisors(n).count());
+        assert_eq!(
+            4 * 6 * 18 * 258 * 642 * 65538 * 6_700_418u128,
+            divisor_sum(n)
+        );
+    }
+}
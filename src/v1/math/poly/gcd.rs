@@ -0,0 +1,400 @@
+use crate::v1::math::poly::series::trimmed;
+use crate::v1::math::poly::{div_rem, multiply};
+use crate::v1::math::{mod_add, mod_inv, mod_mul, mod_sub};
+use std::mem::replace;
+
+/// Degree from which the half-GCD algorithm is used instead of Euclid's one.
+const HALF_GCD_THRESHOLD: usize = 64;
+
+/// 2x2 matrix of polynomials, see [`half_gcd`].
+pub type PolyMatrix = [[Vec<u64>; 2]; 2];
+
+/// Finds the GCD (Greatest Common Divisor) of polynomials modulo a prime `p`.
+/// The result is monic: its leading coefficient equals 1.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::poly::gcd;
+///
+/// // (x + 1)(x + 2) and (x + 1)(x + 3)
+/// let res0 = gcd(&[2, 3, 1], &[3, 4, 1], 7);
+/// // (2x + 2)(x + 3) and (3x + 3)(x + 4)
+/// let res1 = gcd(&[6, 8, 2], &[12, 15, 3], 998_244_353);
+/// let res2 = gcd(&[1, 0, 1], &[1, 1], 7);
+///
+/// assert_eq!(vec![1, 1], res0);
+/// assert_eq!(vec![1, 1], res1);
+/// assert_eq!(vec![1], res2);
+/// ```
+/// ## Corner cases
+/// - GCD of a polynomial and the zero polynomial equals the monic polynomial itself.
+/// - GCD of two zero polynomials equals the zero polynomial (an empty vector).
+/// - Leading zero coefficients are ignored.
+/// ```
+/// use ads_rs::prelude::v1::math::poly::gcd;
+///
+/// assert_eq!(vec![6, 1], gcd(&[3, 6, 0], &[], 11));
+/// assert!(gcd(&[], &[0, 0], 11).is_empty());
+/// ```
+/// # Panics
+/// Panics if `p == 0`. Primality of `p` isn't checked, a composite modulus panics
+/// if a non-invertible leading coefficient appears.
+/// # Implementation details
+/// - Euclid's algorithm is used for small degrees, the division is performed with [`div_rem`].
+/// - The half-GCD algorithm (see [`half_gcd`]) is used for large degrees.
+/// - Time complexity is O(n * log<sub>2</sub><sup>2</sup>(n)), where `n` is the largest degree.
+pub fn gcd(a: &[u64], b: &[u64], p: u64) -> Vec<u64> {
+    assert!(p != 0, "modulus equals 0");
+
+    let (mut a, mut b) = (trimmed(a, p), trimmed(b, p));
+
+    if a.len().max(b.len()) > HALF_GCD_THRESHOLD {
+        let [row, _] = gcd_matrix(&a, &b, p);
+        a = combine(&row, &a, &b, p);
+    } else {
+        while !b.is_empty() {
+            let (_, r) = div_rem(&a, &b, p);
+            a = replace(&mut b, r);
+        }
+    }
+
+    let lc_inv = leading_inv(&a, p);
+    scale(&a, lc_inv, p)
+}
+
+/// Finds an extended GCD (Greatest Common Divisor) of polynomials modulo a prime `p`.
+/// "Extended" means that algorithm will return not only the monic GCD, but two Bezout polynomials `x` and `y` such that the equality
+///
+/// x * a + y * b = gcd(a, b)
+///
+/// holds.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::poly::extended_gcd;
+///
+/// // (x + 1)(x + 2) and (x + 1)(x + 3)
+/// let res0 = extended_gcd(&[2, 3, 1], &[3, 4, 1], 7);
+/// let res1 = extended_gcd(&[1, 0, 1], &[1, 1], 7);
+///
+/// assert_eq!((vec![1, 1], vec![6], vec![1]), res0);
+/// assert_eq!((vec![1], vec![4], vec![4, 3]), res1);
+/// ```
+/// ## Corner cases
+/// - Result of `extended_gcd(0, 0)` equals tuple `(0, 1, 0)`.
+/// - Bezout polynomials are the minimal ones: deg(x) < deg(b) - deg(g) and deg(y) < deg(a) - deg(g),
+///   unless one of the arguments divides the other one.
+/// ```
+/// use ads_rs::prelude::v1::math::poly::extended_gcd;
+///
+/// assert_eq!((vec![], vec![1], vec![]), extended_gcd(&[], &[], 7));
+/// assert_eq!((vec![1, 1], vec![], vec![4]), extended_gcd(&[], &[2, 2], 7));
+/// ```
+/// # Panics
+/// Panics if `p == 0`. Primality of `p` isn't checked, a composite modulus panics
+/// if a non-invertible leading coefficient appears.
+/// # Implementation details
+/// - The remainder sequence is tracked with matrices of quotients (see [`half_gcd`]),
+///   Euclid's algorithm is used for small degrees.
+/// - Time complexity is O(n * log<sub>2</sub><sup>2</sup>(n)), where `n` is the largest degree.
+pub fn extended_gcd(a: &[u64], b: &[u64], p: u64) -> (Vec<u64>, Vec<u64>, Vec<u64>) {
+    assert!(p != 0, "modulus equals 0");
+
+    let (a, b) = (trimmed(a, p), trimmed(b, p));
+    let [row, _] = gcd_matrix(&a, &b, p);
+    let g = combine(&row, &a, &b, p);
+    let lc_inv = leading_inv(&g, p);
+    let [x, y] = row;
+
+    (
+        scale(&g, lc_inv, p),
+        scale(&x, lc_inv, p),
+        scale(&y, lc_inv, p),
+    )
+}
+
+/// Performs a half of Euclid's algorithm on polynomials modulo a prime `p`.
+/// Returns the matrix `M` of the remainder sequence such that `(c, d) = M * (a, b)` are consecutive remainders with
+///
+/// deg(c) ≥ ⌈deg(a) / 2⌉ > deg(d)
+///
+/// The matrix consists of Bezout polynomials: `c = M[0][0] * a + M[0][1] * b` and `d = M[1][0] * a + M[1][1] * b`.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::poly::half_gcd;
+///
+/// // x^4 + 1 and x^3 + x: the remainder sequence continues with 6x^2 + 1 and 2x
+/// let res0 = half_gcd(&[1, 0, 0, 0, 1], &[0, 1, 0, 1], 7);
+/// // x^4 and x^3 + 1: the remainder sequence continues with 6x
+/// let res1 = half_gcd(&[0, 0, 0, 0, 1], &[1, 0, 0, 1], 7);
+///
+/// assert_eq!([[vec![1], vec![0, 6]], [vec![0, 1], vec![1, 0, 6]]], res0);
+/// assert_eq!([[vec![], vec![1]], [vec![1], vec![0, 6]]], res1);
+/// ```
+/// ## Corner case
+/// The identity matrix is returned if `deg(b) < ⌈deg(a) / 2⌉`.
+/// ```
+/// use ads_rs::prelude::v1::math::poly::half_gcd;
+///
+/// assert_eq!([[vec![1], vec![]], [vec![], vec![1]]], half_gcd(&[1, 2, 3], &[4], 7));
+/// ```
+/// # Panics
+/// Panics if `p == 0` or `deg(a) ≤ deg(b)`. Primality of `p` isn't checked, a composite modulus panics
+/// if a non-invertible leading coefficient appears.
+/// # Implementation details
+/// - The first half of the quotients depends only on the highest coefficients, so they are found recursively
+///   for `a / x`<sup>`m`</sup> and `b / x`<sup>`m`</sup>, then one Euclid's step is made and the second half is found in the same way.
+/// - Multiplication is performed with [`multiply`], Euclid's algorithm is used for small degrees.
+/// - Time complexity is O(n * log<sub>2</sub><sup>2</sup>(n)), where `n = deg(a)`.
+pub fn half_gcd(a: &[u64], b: &[u64], p: u64) -> PolyMatrix {
+    assert!(p != 0, "modulus equals 0");
+
+    let (a, b) = (trimmed(a, p), trimmed(b, p));
+    assert!(a.len() > b.len(), "deg(a) isn't greater than deg(b)");
+
+    half_gcd_trimmed(&a, &b, p)
+}
+
+/// [`half_gcd`] for trimmed polynomials.
+fn half_gcd_trimmed(a: &[u64], b: &[u64], p: u64) -> PolyMatrix {
+    let m = (a.len() - 1).div_ceil(2);
+
+    if b.len() <= m {
+        return identity(p);
+    }
+
+    if a.len() <= HALF_GCD_THRESHOLD {
+        let (mut a, mut b) = (a.to_vec(), b.to_vec());
+        let mut res = identity(p);
+
+        while b.len() > m {
+            let (q, r) = div_rem(&a, &b, p);
+            a = replace(&mut b, r);
+            res = euclid_step(res, &q, p);
+        }
+
+        return res;
+    }
+
+    let res = half_gcd_trimmed(&a[m..], &b[m..], p);
+    let (c, d) = apply(&res, a, b, p);
+    if d.len() <= m {
+        return res;
+    }
+
+    let (q, e) = div_rem(&c, &d, p);
+    let res = euclid_step(res, &q, p);
+    if e.len() <= m {
+        return res;
+    }
+
+    // the recursion for d / x^k stops below degree deg(d) - m, which is m - k, so the remainders stop below degree m
+    let k = 2 * m - (d.len() - 1);
+    mul_matrix(&half_gcd_trimmed(&d[k..], &e[k..], p), &res, p)
+}
+
+/// Finds the matrix `M` of the whole remainder sequence such that `M * (a, b) = (g, 0)`.
+fn gcd_matrix(a: &[u64], b: &[u64], p: u64) -> PolyMatrix {
+    let (mut a, mut b) = (a.to_vec(), b.to_vec());
+    let mut res = identity(p);
+
+    while !b.is_empty() {
+        if a.len() > b.len() && a.len() > HALF_GCD_THRESHOLD {
+            let m = half_gcd_trimmed(&a, &b, p);
+            (a, b) = apply(&m, &a, &b, p);
+            res = mul_matrix(&m, &res, p);
+
+            if b.is_empty() {
+                break;
+            }
+        }
+
+        let (q, r) = div_rem(&a, &b, p);
+        a = replace(&mut b, r);
+        res = euclid_step(res, &q, p);
+    }
+
+    res
+}
+
+fn identity(p: u64) -> PolyMatrix {
+    let one = trimmed(&[1], p);
+
+    [[one.clone(), vec![]], [vec![], one]]
+}
+
+/// Multiplies `m` by the matrix of a single Euclid's step with quotient `q` from the left.
+fn euclid_step(m: PolyMatrix, q: &[u64], p: u64) -> PolyMatrix {
+    let [row0, row1] = m;
+    let next = [
+        sub(&row0[0], &multiply(q, &row1[0], p), p),
+        sub(&row0[1], &multiply(q, &row1[1], p), p),
+    ];
+
+    [row1, next]
+}
+
+fn mul_matrix(lhs: &PolyMatrix, rhs: &PolyMatrix, p: u64) -> PolyMatrix {
+    let cell = |i: usize, j: usize| {
+        add(
+            &multiply(&lhs[i][0], &rhs[0][j], p),
+            &multiply(&lhs[i][1], &rhs[1][j], p),
+            p,
+        )
+    };
+
+    [[cell(0, 0), cell(0, 1)], [cell(1, 0), cell(1, 1)]]
+}
+
+fn apply(m: &PolyMatrix, a: &[u64], b: &[u64], p: u64) -> (Vec<u64>, Vec<u64>) {
+    (combine(&m[0], a, b, p), combine(&m[1], a, b, p))
+}
+
+/// Finds `row[0] * a + row[1] * b`.
+fn combine(row: &[Vec<u64>; 2], a: &[u64], b: &[u64], p: u64) -> Vec<u64> {
+    add(&multiply(&row[0], a, p), &multiply(&row[1], b, p), p)
+}
+
+fn add(a: &[u64], b: &[u64], p: u64) -> Vec<u64> {
+    let mut res = a.to_vec();
+    res.resize(a.len().max(b.len()), 0);
+    for (x, &y) in res.iter_mut().zip(b) {
+        *x = mod_add(*x, y, p);
+    }
+
+    trimmed(&res, p)
+}
+
+fn sub(a: &[u64], b: &[u64], p: u64) -> Vec<u64> {
+    let mut res = a.to_vec();
+    res.resize(a.len().max(b.len()), 0);
+    for (x, &y) in res.iter_mut().zip(b) {
+        *x = mod_sub(*x, y, p);
+    }
+
+    trimmed(&res, p)
+}
+
+fn scale(a: &[u64], c: u64, p: u64) -> Vec<u64> {
+    a.iter().map(|&x| mod_mul(x, c, p)).collect()
+}
+
+/// Finds the inverse of the leading coefficient, 1 for the zero polynomial.
+fn leading_inv(a: &[u64], p: u64) -> u64 {
+    a.last().map_or(1, |&lc| {
+        mod_inv(lc, p).expect("leading coefficient isn't invertible")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v1::math::prime::SplitMix64;
+
+    fn random_poly(rng: &mut SplitMix64, len: usize, p: u64) -> Vec<u64> {
+        (0..len).map(|_| rng.next() % p).collect()
+    }
+
+    /// Euclid's algorithm with tracking of Bezout polynomials.
+    fn euclid(a: &[u64], b: &[u64], p: u64) -> (Vec<u64>, Vec<u64>, Vec<u64>) {
+        let (mut r0, mut r1) = (trimmed(a, p), trimmed(b, p));
+        let (mut x0, mut x1) = (vec![1], vec![]);
+        let (mut y0, mut y1) = (vec![], vec![1]);
+
+        while !r1.is_empty() {
+            let (q, r) = div_rem(&r0, &r1, p);
+            r0 = replace(&mut r1, r);
+            let x = sub(&x0, &multiply(&q, &x1, p), p);
+            x0 = replace(&mut x1, x);
+            let y = sub(&y0, &multiply(&q, &y1, p), p);
+            y0 = replace(&mut y1, y);
+        }
+
+        let lc_inv = leading_inv(&r0, p);
+        (
+            scale(&r0, lc_inv, p),
+            scale(&x0, lc_inv, p),
+            scale(&y0, lc_inv, p),
+        )
+    }
+
+    #[test]
+    fn extended_gcd_matches_euclid() {
+        let mut rng = SplitMix64(42);
+
+        for p in [2, 7, 998_244_353, 18_446_744_073_709_551_557] {
+            for (n, k, common) in [
+                (0, 5, 0),
+                (10, 10, 3),
+                (50, 30, 0),
+                (100, 99, 20),
+                (300, 200, 150),
+                (500, 500, 1),
+                (700, 300, 0),
+            ] {
+                let c = random_poly(&mut rng, common + 1, p);
+                let a = multiply(&c, &random_poly(&mut rng, n, p), p);
+                let b = multiply(&c, &random_poly(&mut rng, k, p), p);
+
+                let expected = euclid(&a, &b, p);
+                let result = extended_gcd(&a, &b, p);
+
+                assert_eq!(expected, result, "p = {p}, n = {n}, k = {k}");
+                assert_eq!(expected.0, gcd(&a, &b, p), "p = {p}, n = {n}, k = {k}");
+                assert_eq!(
+                    result.0,
+                    combine(&[result.1.clone(), result.2.clone()], &a, &b, p)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn half_gcd_works() {
+        let mut rng = SplitMix64(42);
+
+        for p in [3, 998_244_353, 18_446_744_073_709_551_557] {
+            for (n, k) in [(1, 0), (2, 2), (65, 64), (100, 60), (257, 256), (1000, 999)] {
+                let mut a = random_poly(&mut rng, n + 1, p);
+                a[n] = 1;
+                let b = trimmed(&random_poly(&mut rng, k, p), p);
+
+                let m = half_gcd(&a, &b, p);
+                let (c, d) = apply(&m, &a, &b, p);
+                let bound = n.div_ceil(2);
+
+                assert!(c.len() > bound && d.len() <= bound, "p = {p}, n = {n}");
+
+                // c and d are consecutive remainders, so the GCD is kept
+                assert_eq!(gcd(&a, &b, p), gcd(&c, &d, p), "p = {p}, n = {n}");
+            }
+        }
+    }
+
+    #[test]
+    fn gcd_works() {
+        // arrange
+        let test_suits = [
+            // x^2 - 1 and x^2 + 2x + 1 modulo 5
+            ((vec![4, 0, 1], vec![1, 2, 1], 5), vec![1, 1]),
+            // x^p - x vanishes everywhere modulo p
+            (
+                (vec![0, 6, 0, 0, 0, 0, 0, 1], vec![1, 3, 3, 1], 7),
+                vec![1, 1],
+            ),
+            // Coprime polynomials
+            ((vec![1, 1], vec![2, 1], 2), vec![1]),
+            // Constants
+            ((vec![3], vec![5], 11), vec![1]),
+        ];
+
+        // act
+        let result: Vec<Vec<u64>> = test_suits
+            .iter()
+            .map(|((a, b, p), _)| gcd(a, b, *p))
+            .collect();
+
+        // assert
+        for i in 0..test_suits.len() {
+            assert_eq!(test_suits[i].1, result[i]);
+        }
+    }
+}
@@ -0,0 +1,21 @@
+//! Polynomials with coefficients modulo a number.
+//!
+//! A polynomial is a slice of coefficients from the lowest degree to the highest one,
+//! e.g. `[1, 0, 3]` is 1 + 3x<sup>2</sup>, and the zero polynomial is the empty slice.
+//!
+//! - [`ntt`] and [`multiply`] implement the Number Theoretic Transform and fast multiplication.
+//! - [`inverse_series`], [`log_series`] and [`exp_series`] work with truncated power series,
+//!   [`div_rem`] divides polynomials with remainder.
+//! - [`gcd`], [`extended_gcd`] and [`half_gcd`] find GCD of polynomials modulo a prime.
+//! - [`Polynomial`] wraps the coefficients and a compile-time prime modulus
+//!   into a type implementing [`EuclideanDomain`](crate::v1::math::EuclideanDomain).
+
+mod gcd;
+mod ntt;
+mod polynomial;
+mod series;
+
+pub use gcd::*;
+pub use ntt::*;
+pub use polynomial::*;
+pub use series::*;
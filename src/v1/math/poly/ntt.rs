@@ -0,0 +1,377 @@
+use crate::v1::math::{
+    is_prime, mod_add, mod_inv, mod_mul, mod_sub, primitive_root, DynModInt, Modulus,
+};
+
+/// Length of the shorter operand up to which the schoolbook multiplication is used.
+const NAIVE_THRESHOLD: usize = 32;
+
+/// NTT-friendly primes p = c * 2<sup>k</sup> + 1 with their primitive roots used by [`multiply`]
+/// for arbitrary moduli. Their product exceeds 2<sup>185</sup>, so it bounds every coefficient
+/// of a product of polynomials with coefficients below 2<sup>64</sup> and lengths below 2<sup>55</sup>.
+const CRT_PRIMES: [(u64, u64); 3] = [
+    // 29 * 2^57 + 1
+    (4_179_340_454_199_820_289, 3),
+    // 87 * 2^56 + 1
+    (6_269_010_681_299_730_433, 5),
+    // 69 * 2^55 + 1
+    (2_485_986_994_308_513_793, 5),
+];
+
+/// Performs the Number Theoretic Transform modulo a prime `p` in place:
+/// `values[i]` is replaced by the polynomial evaluated at ω<sup>i</sup>, where ω is a root of unity of order `values.len()`.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::poly::{inverse_ntt, ntt};
+///
+/// let mut values = vec![1, 2, 3, 4];
+///
+/// ntt(&mut values, 998_244_353);
+/// assert_eq!(vec![10, 173_167_434, 998_244_351, 825_076_915], values);
+///
+/// inverse_ntt(&mut values, 998_244_353);
+/// assert_eq!(vec![1, 2, 3, 4], values);
+/// ```
+/// ## Corner case
+/// The transform of a single value is the value itself.
+/// ```
+/// use ads_rs::prelude::v1::math::poly::ntt;
+///
+/// let mut values = vec![5];
+/// ntt(&mut values, 7);
+///
+/// assert_eq!(vec![5], values);
+/// ```
+/// # Panics
+/// Panics if `values.len()` isn't a power of two dividing `p - 1`. Primality of `p` isn't checked.
+/// # Implementation details
+/// - ω equals g<sup>(p - 1) / n</sup> for the smallest primitive root `g` (from [`primitive_root`]).
+/// - Iterative Cooley–Tukey algorithm is used, arithmetic is performed with Montgomery multiplication (from [`DynModInt`]).
+/// - Time complexity is O(n * log<sub>2</sub>(n))
+pub fn ntt(values: &mut [u64], p: u64) {
+    transform_in_place(values, p, false);
+}
+
+/// Performs the inverse Number Theoretic Transform modulo a prime `p` in place, see [`ntt`].
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::poly::inverse_ntt;
+///
+/// let mut values = vec![10, 173_167_434, 998_244_351, 825_076_915];
+/// inverse_ntt(&mut values, 998_244_353);
+///
+/// assert_eq!(vec![1, 2, 3, 4], values);
+/// ```
+/// # Panics
+/// Panics if `values.len()` isn't a power of two dividing `p - 1`. Primality of `p` isn't checked.
+/// # Implementation details
+/// - The transform with ω<sup>-1</sup> is divided by `n`.
+/// - Time complexity is O(n * log<sub>2</sub>(n))
+pub fn inverse_ntt(values: &mut [u64], p: u64) {
+    transform_in_place(values, p, true);
+}
+
+/// Multiplies polynomials with coefficients modulo `m`.
+/// Returns `a.len() + b.len() - 1` coefficients, or an empty vector if any operand is empty.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::poly::multiply;
+///
+/// // (1 + 2x)(3 + 4x) = 3 + 10x + 8x^2
+/// let res0 = multiply(&[1, 2], &[3, 4], 998_244_353);
+/// let res1 = multiply(&[1, 2], &[3, 4], 7);
+/// let res2 = multiply(&[u64::MAX - 1; 40], &[u64::MAX - 1; 40], u64::MAX);
+///
+/// assert_eq!(vec![3, 10, 8], res0);
+/// assert_eq!(vec![3, 3, 1], res1);
+/// assert_eq!(1, res2[0]);
+/// assert_eq!(40, res2[39]);
+/// ```
+/// ## Corner cases
+/// - The product with the zero polynomial is the zero polynomial (an empty vector).
+/// - The modulus doesn't have to be prime.
+/// ```
+/// use ads_rs::prelude::v1::math::poly::multiply;
+///
+/// assert!(multiply(&[1, 2, 3], &[], 7).is_empty());
+/// assert_eq!(vec![0, 0, 0], multiply(&[1, 1], &[1, 1], 1));
+/// assert_eq!(vec![1, 1 << 62, 0], multiply(&[1, 1 << 61], &[1, 1 << 61], 1 << 63));
+/// ```
+/// # Panics
+/// Panics if `m == 0`.
+/// # Implementation details
+/// - Short polynomials are multiplied in the schoolbook way.
+/// - If `m` is a prime and 2<sup>k</sup> ≥ `a.len() + b.len() - 1` divides `m - 1`, the product is computed with a single [`ntt`].
+/// - Otherwise the product is computed modulo three NTT-friendly primes of 62 bits
+///   and the exact coefficients are reconstructed with Garner's algorithm (mixed radix CRT).
+/// - Time complexity is O(n * log<sub>2</sub>(n)), where `n = a.len() + b.len()`.
+pub fn multiply(a: &[u64], b: &[u64], m: u64) -> Vec<u64> {
+    assert!(m != 0, "modulus equals 0");
+
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+
+    if a.len().min(b.len()) <= NAIVE_THRESHOLD {
+        return multiply_naive(a, b, m);
+    }
+
+    let size = (a.len() + b.len() - 1).next_power_of_two() as u64;
+    if (m - 1).is_multiple_of(size) && is_prime(m) {
+        return convolve(a, b, m);
+    }
+
+    let [(p1, _), (p2, _), (p3, _)] = CRT_PRIMES;
+    let (r1, r2, r3) = (convolve(a, b, p1), convolve(a, b, p2), convolve(a, b, p3));
+
+    // x = r1 + p1 * k2 + p1 * p2 * k3, where 0 <= k2 < p2 and 0 <= k3 < p3
+    let p1_inv = mod_inv(p1 % p2, p2).unwrap();
+    let p1p2_inv = mod_inv(mod_mul(p1, p2, p3), p3).unwrap();
+    let p1p2 = mod_mul(p1, p2, m);
+
+    (0..r1.len())
+        .map(|i| {
+            let k2 = mod_mul(mod_sub(r2[i], r1[i] % p2, p2), p1_inv, p2);
+            let x12 = mod_add(r1[i] % p3, mod_mul(p1, k2, p3), p3);
+            let k3 = mod_mul(mod_sub(r3[i], x12, p3), p1p2_inv, p3);
+
+            mod_add(
+                mod_add(r1[i] % m, mod_mul(p1, k2, m), m),
+                mod_mul(p1p2, k3, m),
+                m,
+            )
+        })
+        .collect()
+}
+
+/// Multiplies polynomials in the schoolbook way.
+fn multiply_naive(a: &[u64], b: &[u64], m: u64) -> Vec<u64> {
+    let mut res = vec![0; a.len() + b.len() - 1];
+
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            res[i + j] = mod_add(res[i + j], mod_mul(x, y, m), m);
+        }
+    }
+
+    res
+}
+
+/// Multiplies polynomials modulo an NTT-friendly prime `p`.
+fn convolve(a: &[u64], b: &[u64], p: u64) -> Vec<u64> {
+    let len = a.len() + b.len() - 1;
+    let size = len.next_power_of_two();
+    let modulus = Modulus::new(p);
+
+    let mut fa = to_mod_ints(a, size, &modulus);
+    let mut fb = to_mod_ints(b, size, &modulus);
+    let root = root_of_unity(size, &modulus);
+
+    transform(&mut fa, root);
+    transform(&mut fb, root);
+    for (x, &y) in fa.iter_mut().zip(&fb) {
+        *x *= y;
+    }
+    inverse_transform(&mut fa, root, &modulus);
+
+    fa[..len].iter().map(|x| x.value()).collect()
+}
+
+fn transform_in_place(values: &mut [u64], p: u64, inverse: bool) {
+    let n = values.len();
+    assert!(
+        n.is_power_of_two() && (p - 1).is_multiple_of(n as u64),
+        "length isn't a power of two dividing p - 1"
+    );
+
+    let modulus = Modulus::new(p);
+    let mut mod_ints = to_mod_ints(values, n, &modulus);
+    let root = root_of_unity(n, &modulus);
+
+    if inverse {
+        inverse_transform(&mut mod_ints, root, &modulus);
+    } else {
+        transform(&mut mod_ints, root);
+    }
+
+    for (value, x) in values.iter_mut().zip(mod_ints) {
+        *value = x.value();
+    }
+}
+
+/// Converts coefficients into values modulo `modulus` padded with zeros up to `size`.
+fn to_mod_ints<'a>(values: &[u64], size: usize, modulus: &'a Modulus) -> Vec<DynModInt<'a>> {
+    let mut res: Vec<DynModInt> = values.iter().map(|&x| DynModInt::new(x, modulus)).collect();
+    res.resize(size, DynModInt::new(0, modulus));
+
+    res
+}
+
+/// Finds a root of unity of order `n` modulo a prime.
+fn root_of_unity(n: usize, modulus: &Modulus) -> DynModInt<'_> {
+    let p = modulus.value();
+    let g = CRT_PRIMES
+        .iter()
+        .find(|&&(q, _)| q == p)
+        .map(|&(_, g)| g)
+        .or_else(|| primitive_root(p))
+        .expect("modulus has no primitive root");
+
+    DynModInt::new(g, modulus).pow((p - 1) / n as u64)
+}
+
+/// Iterative Cooley–Tukey transform with a root of unity of order `values.len()`.
+fn transform<'a>(values: &mut [DynModInt<'a>], root: DynModInt<'a>) {
+    let n = values.len();
+
+    // bit-reversal permutation
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let half = len / 2;
+        let step = root.pow((n / len) as u64);
+        let twiddles: Vec<DynModInt<'a>> = (0..half)
+            .scan(step.pow(0), |w, _| {
+                let res = *w;
+                *w *= step;
+                Some(res)
+            })
+            .collect();
+
+        for chunk in values.chunks_exact_mut(len) {
+            let (lo, hi) = chunk.split_at_mut(half);
+            for k in 0..half {
+                let (u, v) = (lo[k], hi[k] * twiddles[k]);
+                lo[k] = u + v;
+                hi[k] = u - v;
+            }
+        }
+
+        len <<= 1;
+    }
+}
+
+/// Inverse of [`transform`] with the same root of unity.
+fn inverse_transform<'a>(values: &mut [DynModInt<'a>], root: DynModInt<'a>, modulus: &'a Modulus) {
+    transform(values, root.inv().unwrap());
+
+    let n_inv = DynModInt::new(values.len() as u64, modulus).inv().unwrap();
+    for x in values.iter_mut() {
+        *x *= n_inv;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v1::math::prime::SplitMix64;
+
+    fn random_poly(rng: &mut SplitMix64, len: usize, m: u64) -> Vec<u64> {
+        (0..len).map(|_| rng.next() % m).collect()
+    }
+
+    #[test]
+    fn ntt_roundtrip_works() {
+        let mut rng = SplitMix64(42);
+
+        for (p, max_log) in [(998_244_353, 12), (7681, 9), (CRT_PRIMES[0].0, 12), (17, 4)] {
+            for log in 0..=max_log {
+                let values = random_poly(&mut rng, 1 << log, p);
+                let mut transformed = values.clone();
+
+                ntt(&mut transformed, p);
+                inverse_ntt(&mut transformed, p);
+
+                assert_eq!(values, transformed);
+            }
+        }
+    }
+
+    #[test]
+    fn ntt_evaluates_polynomial() {
+        let (p, n) = (998_244_353, 16);
+        let mut rng = SplitMix64(7);
+        let values = random_poly(&mut rng, n, p);
+        let modulus = Modulus::new(p);
+        let root = root_of_unity(n, &modulus);
+
+        let mut transformed = values.clone();
+        ntt(&mut transformed, p);
+
+        for (i, &y) in transformed.iter().enumerate() {
+            let x = root.pow(i as u64);
+            let expected = values
+                .iter()
+                .rev()
+                .fold(DynModInt::new(0, &modulus), |acc, &c| {
+                    acc * x + DynModInt::new(c, &modulus)
+                });
+
+            assert_eq!(expected.value(), y);
+        }
+    }
+
+    #[test]
+    fn multiply_matches_naive() {
+        let mut rng = SplitMix64(42);
+        let moduli = [
+            998_244_353,
+            1_000_000_007,
+            1 << 63,
+            u64::MAX,
+            u64::MAX - 58,
+            4_179_340_454_199_820_289,
+            2,
+            1,
+        ];
+
+        for m in moduli {
+            for (n, k) in [(1, 100), (33, 33), (100, 57), (300, 1000), (513, 512)] {
+                let a = random_poly(&mut rng, n, m);
+                let b = random_poly(&mut rng, k, m);
+
+                assert_eq!(
+                    multiply_naive(&a, &b, m),
+                    multiply(&a, &b, m),
+                    "m = {m}, n = {n}, k = {k}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn multiply_extreme_values_works() {
+        // arrange
+        let n = 1 << 12;
+        let test_suits = [u64::MAX, 1 << 63, 998_244_353, 3];
+
+        // act
+        let result: Vec<Vec<u64>> = test_suits
+            .iter()
+            .map(|&m| multiply(&vec![m - 1; n], &vec![m - 1; n], m))
+            .collect();
+
+        // assert
+        for i in 0..test_suits.len() {
+            // (m - 1)^2 = 1 (mod m), so coefficients count the pairs of terms
+            let m = test_suits[i];
+            let expected: Vec<u64> = (0..2 * n - 1)
+                .map(|k| (k.min(2 * n - 2 - k) + 1) as u64 % m)
+                .collect();
+
+            assert_eq!(expected, result[i]);
+        }
+    }
+}
@@ -0,0 +1,262 @@
+use crate::v1::math::poly::series::trimmed;
+use crate::v1::math::poly::{div_rem, multiply};
+use crate::v1::math::{mod_add, mod_inv, mod_sub, EuclideanDomain};
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+/// A polynomial with coefficients modulo a prime `P`, where `P` is a compile-time constant.
+///
+/// Polynomials over a field form an Euclidean domain with the degree as the Euclidean function,
+/// so [`euclidean_gcd`](crate::v1::math::euclidean_gcd) and the related functions work with them.
+/// Coefficients are stored from the lowest degree to the highest one without leading zeros,
+/// so the derived `Eq` and `Hash` are consistent with the value.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::euclidean_gcd;
+/// use ads_rs::prelude::v1::math::poly::Polynomial;
+///
+/// type Poly = Polynomial<7>;
+///
+/// // (x + 1)(x + 2) and (x + 1)(x + 3)
+/// let a = Poly::new(&[2, 3, 1]);
+/// let b = Poly::new(&[3, 4, 1]);
+///
+/// assert_eq!(Poly::new(&[5, 7, 2]), a.clone() + b.clone());
+/// assert_eq!(Poly::new(&[6, 6]), a.clone() - b.clone());
+/// assert_eq!(Poly::new(&[6, 17, 17, 7, 1]), a.clone() * b.clone());
+/// assert_eq!((Poly::new(&[1]), Poly::new(&[6, 6])), (a.clone() / b.clone(), a.clone() % b.clone()));
+/// assert_eq!(Poly::new(&[1, 1]), euclidean_gcd(&a, &b));
+/// assert_eq!(Some(2), a.degree());
+/// ```
+/// ## Corner cases
+/// - The zero polynomial has no coefficients and no degree.
+/// - The canonical associate is the monic polynomial.
+/// ```
+/// use ads_rs::prelude::v1::math::EuclideanDomain;
+/// use ads_rs::prelude::v1::math::poly::Polynomial;
+///
+/// assert_eq!(None, Polynomial::<7>::new(&[0, 7, 14]).degree());
+/// assert_eq!(Polynomial::<7>::new(&[4]), Polynomial::<7>::new(&[1, 2]).canonical_unit());
+/// ```
+/// A zero modulus is rejected at compile time.
+/// ```compile_fail
+/// use ads_rs::prelude::v1::math::poly::Polynomial;
+///
+/// let _ = Polynomial::<0>::new(&[1]);
+/// ```
+/// # Panics
+/// Division panics if the divisor is the zero polynomial. Primality of `P` isn't checked,
+/// a composite modulus panics if a non-invertible leading coefficient appears.
+/// # Implementation details
+/// - Operations are performed with [`multiply`] and [`div_rem`].
+/// - The Euclidean function is the number of coefficients, i.e. the degree plus one.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Polynomial<const P: u64> {
+    coeffs: Vec<u64>,
+}
+
+impl<const P: u64> Polynomial<P> {
+    const NONZERO_MODULUS: () = assert!(P != 0, "modulus equals 0");
+
+    /// Creates a polynomial from coefficients from the lowest degree to the highest one,
+    /// they are reduced modulo `P` and leading zeros are removed.
+    pub fn new(coeffs: &[u64]) -> Self {
+        let () = Self::NONZERO_MODULUS;
+
+        Self {
+            coeffs: trimmed(coeffs, P),
+        }
+    }
+
+    /// Returns the modulus `P`.
+    pub fn modulus() -> u64 {
+        P
+    }
+
+    /// Returns the coefficients from the lowest degree to the highest one, empty for the zero polynomial.
+    pub fn coeffs(&self) -> &[u64] {
+        &self.coeffs
+    }
+
+    /// Returns the degree, `None` for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.coeffs.len().checked_sub(1)
+    }
+}
+
+impl<const P: u64> EuclideanDomain for Polynomial<P> {
+    type Norm = usize;
+
+    fn zero() -> Self {
+        Self::new(&[])
+    }
+
+    fn one() -> Self {
+        Self::new(&[1])
+    }
+
+    fn is_zero(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+
+    fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        let (quot, rem) = div_rem(&self.coeffs, &rhs.coeffs, P);
+
+        (Self { coeffs: quot }, Self { coeffs: rem })
+    }
+
+    fn norm(&self) -> Self::Norm {
+        self.coeffs.len()
+    }
+
+    fn canonical_unit(&self) -> Self {
+        match self.coeffs.last() {
+            Some(&lc) => {
+                Self::new(&[mod_inv(lc, P).expect("leading coefficient isn't invertible")])
+            }
+            None => Self::one(),
+        }
+    }
+}
+
+impl<const P: u64> Add for Polynomial<P> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let (mut long, short) = if self.coeffs.len() >= rhs.coeffs.len() {
+            (self.coeffs, rhs.coeffs)
+        } else {
+            (rhs.coeffs, self.coeffs)
+        };
+
+        for (a, b) in long.iter_mut().zip(short) {
+            *a = mod_add(*a, b, P);
+        }
+
+        Self::new(&long)
+    }
+}
+
+impl<const P: u64> Sub for Polynomial<P> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl<const P: u64> Mul for Polynomial<P> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(&multiply(&self.coeffs, &rhs.coeffs, P))
+    }
+}
+
+impl<const P: u64> Div for Polynomial<P> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_rem(&rhs).0
+    }
+}
+
+impl<const P: u64> Rem for Polynomial<P> {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.div_rem(&rhs).1
+    }
+}
+
+impl<const P: u64> Neg for Polynomial<P> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            coeffs: self.coeffs.iter().map(|&c| mod_sub(0, c, P)).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v1::math::poly::{extended_gcd, gcd};
+    use crate::v1::math::prime::SplitMix64;
+    use crate::v1::math::{euclidean_extended_gcd, euclidean_gcd, euclidean_lcm};
+
+    const P: u64 = 998_244_353;
+
+    fn random(rng: &mut SplitMix64, max_len: u64) -> Polynomial<P> {
+        let len = rng.next() % (max_len + 1);
+
+        Polynomial::new(&(0..len).map(|_| rng.next() % P).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn matches_poly_gcd() {
+        let mut rng = SplitMix64(42);
+
+        for _ in 0..200 {
+            let c = random(&mut rng, 4);
+            let a = c.clone() * random(&mut rng, 19);
+            let b = c.clone() * random(&mut rng, 19);
+            let (g, x, y) = euclidean_extended_gcd(&a, &b);
+            let (eg, ex, ey) = extended_gcd(a.coeffs(), b.coeffs(), P);
+
+            assert_eq!(gcd(a.coeffs(), b.coeffs(), P), euclidean_gcd(&a, &b).coeffs);
+            assert_eq!((eg, ex, ey), (g.coeffs, x.coeffs, y.coeffs));
+        }
+    }
+
+    #[test]
+    fn lcm_works() {
+        let mut rng = SplitMix64(42);
+
+        for _ in 0..100 {
+            let a = random(&mut rng, 10);
+            let b = random(&mut rng, 10);
+            let lcm = euclidean_lcm(&a, &b);
+            let g = euclidean_gcd(&a, &b);
+
+            if a.is_zero() || b.is_zero() {
+                assert!(lcm.is_zero());
+                continue;
+            }
+
+            assert!((lcm.clone() % a.clone()).is_zero());
+            assert!((lcm.clone() % b.clone()).is_zero());
+            assert_eq!(
+                lcm.coeffs.len() + g.coeffs.len(),
+                a.coeffs.len() + b.coeffs.len()
+            );
+            assert_eq!(Some(&1), lcm.coeffs.last());
+        }
+    }
+
+    #[test]
+    fn arithmetic_works() {
+        // arrange
+        let test_suits = [
+            // cancellation of the leading coefficients
+            (vec![1, 2, 3], vec![4, 5, 4], vec![5, 0, 0]),
+            // the zero polynomial
+            (vec![], vec![1, 1], vec![1, 1]),
+            // reduction of the coefficients
+            (vec![10, 20], vec![], vec![3, 6]),
+        ];
+
+        // act
+        let result: Vec<Polynomial<7>> = test_suits
+            .iter()
+            .map(|t| Polynomial::new(&t.0) + Polynomial::new(&t.1))
+            .collect();
+
+        // assert
+        for i in 0..test_suits.len() {
+            let (a, b, expected) = &test_suits[i];
+            assert_eq!(Polynomial::new(expected), result[i]);
+            assert_eq!(Polynomial::new(a), result[i].clone() - Polynomial::new(b));
+        }
+    }
+}
@@ -0,0 +1,323 @@
+use crate::v1::math::poly::multiply;
+use crate::v1::math::{mod_add, mod_inv, mod_mul, mod_sub};
+
+/// Finds the first `n` coefficients of the power series 1 / a(x) modulo `m`.
+/// Returns `None` if the constant term of `a` isn't invertible modulo `m`.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::poly::inverse_series;
+///
+/// // 1 / (1 - x) = 1 + x + x^2 + ...
+/// let res0 = inverse_series(&[1, 998_244_352], 5, 998_244_353);
+/// // 1 / (1 - x - x^2) generates Fibonacci numbers
+/// let res1 = inverse_series(&[1, 6, 6], 8, 7);
+/// let res2 = inverse_series(&[2, 1], 3, 4);
+///
+/// assert_eq!(Some(vec![1, 1, 1, 1, 1]), res0);
+/// assert_eq!(Some(vec![1, 1, 2, 3, 5, 1, 6, 0]), res1);
+/// assert_eq!(None, res2);
+/// ```
+/// ## Corner cases
+/// - The modulus doesn't have to be prime.
+/// - An empty series is returned for `n == 0`, even if `a` isn't invertible.
+/// ```
+/// use ads_rs::prelude::v1::math::poly::inverse_series;
+///
+/// assert_eq!(Some(vec![7, 1, 3]), inverse_series(&[3, 1], 3, 10));
+/// assert_eq!(Some(vec![]), inverse_series(&[], 0, 7));
+/// ```
+/// # Panics
+/// Panics if `m == 0`.
+/// # Implementation details
+/// - Newton's iteration b := b * (2 - a * b) doubles the count of correct coefficients,
+///   multiplication is performed with [`multiply`].
+/// - Time complexity is O(n * log<sub>2</sub>(n))
+pub fn inverse_series(a: &[u64], n: usize, m: u64) -> Option<Vec<u64>> {
+    assert!(m != 0, "modulus equals 0");
+
+    if n == 0 {
+        return Some(vec![]);
+    }
+
+    let mut res = vec![mod_inv(*a.first()? % m, m)?];
+
+    while res.len() < n {
+        let len = (res.len() * 2).min(n);
+
+        // 2 - a * b
+        let mut correction = multiply(&a[..len.min(a.len())], &res, m);
+        correction.resize(len, 0);
+        for c in correction.iter_mut() {
+            *c = mod_sub(0, *c, m);
+        }
+        correction[0] = mod_add(correction[0], 2, m);
+
+        res = multiply(&res, &correction, m);
+        res.truncate(len);
+    }
+
+    Some(res)
+}
+
+/// Finds the first `n` coefficients of the power series ln(a(x)) modulo a prime `p`.
+/// Returns `None` if the constant term of `a` isn't 1.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::poly::log_series;
+///
+/// // ln(1 / (1 - x)) = x + x^2 / 2 + x^3 / 3 + ...
+/// let res0 = log_series(&[1, 1, 1, 1, 1], 4, 7);
+/// let res1 = log_series(&[2, 1], 4, 7);
+///
+/// assert_eq!(Some(vec![0, 1, 4, 5]), res0);
+/// assert_eq!(None, res1);
+/// ```
+/// # Panics
+/// Panics if `p < n`, because the integration divides by numbers up to `n - 1`. Primality of `p` isn't checked.
+/// # Implementation details
+/// - ln(a) is the integral of a' / a, the inverse is found with [`inverse_series`].
+/// - Time complexity is O(n * log<sub>2</sub>(n))
+pub fn log_series(a: &[u64], n: usize, p: u64) -> Option<Vec<u64>> {
+    assert!(p >= n as u64, "p is less than n");
+
+    if n == 0 {
+        return Some(vec![]);
+    }
+
+    if a.first().map(|&c| c % p) != Some(1 % p) {
+        return None;
+    }
+
+    let a = &a[..n.min(a.len())];
+    let derivative: Vec<u64> = (1..a.len()).map(|i| mod_mul(a[i], i as u64, p)).collect();
+    let mut quotient = multiply(&derivative, &inverse_series(a, n, p)?, p);
+    quotient.resize(n - 1, 0);
+
+    let inverses = inverses_up_to(n, p);
+    let mut res = vec![0; n];
+    for i in 1..n {
+        res[i] = mod_mul(quotient[i - 1], inverses[i], p);
+    }
+
+    Some(res)
+}
+
+/// Finds the first `n` coefficients of the power series exp(a(x)) modulo a prime `p`.
+/// Returns `None` if the constant term of `a` isn't 0.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::poly::exp_series;
+///
+/// // exp(x) = 1 + x + x^2 / 2 + x^3 / 6 + ...
+/// let res0 = exp_series(&[0, 1], 4, 7);
+/// let res1 = exp_series(&[1, 1], 4, 7);
+///
+/// assert_eq!(Some(vec![1, 1, 4, 6]), res0);
+/// assert_eq!(None, res1);
+/// ```
+/// # Panics
+/// Panics if `p < n`, because the integration divides by numbers up to `n - 1`. Primality of `p` isn't checked.
+/// # Implementation details
+/// - Newton's iteration b := b * (1 - ln(b) + a) doubles the count of correct coefficients,
+///   the logarithm is found with [`log_series`].
+/// - Time complexity is O(n * log<sub>2</sub>(n))
+pub fn exp_series(a: &[u64], n: usize, p: u64) -> Option<Vec<u64>> {
+    assert!(p >= n as u64, "p is less than n");
+
+    if n == 0 {
+        return Some(vec![]);
+    }
+
+    if a.first().is_some_and(|&c| c % p != 0) {
+        return None;
+    }
+
+    let mut res = vec![1 % p];
+
+    while res.len() < n {
+        let len = (res.len() * 2).min(n);
+
+        // 1 - ln(b) + a
+        let mut correction = log_series(&res, len, p)?;
+        for (i, c) in correction.iter_mut().enumerate() {
+            *c = mod_sub(a.get(i).map_or(0, |&x| x % p), *c, p);
+        }
+        correction[0] = mod_add(correction[0], 1, p);
+
+        res = multiply(&res, &correction, p);
+        res.truncate(len);
+    }
+
+    Some(res)
+}
+
+/// Divides polynomials with remainder modulo `m`: finds `q` and `r` such that `a = b * q + r` and deg(r) < deg(b).
+/// Returns `(q, r)` without leading zero coefficients.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::poly::div_rem;
+///
+/// // x^3 + 2x + 5 = (x + 1)(x^2 - x + 3) + 2
+/// let res0 = div_rem(&[5, 2, 0, 1], &[1, 1], 998_244_353);
+/// let res1 = div_rem(&[1, 2, 3], &[0, 0, 0, 1], 7);
+///
+/// assert_eq!((vec![3, 998_244_352, 1], vec![2]), res0);
+/// assert_eq!((vec![], vec![1, 2, 3]), res1);
+/// ```
+/// ## Corner cases
+/// - Leading zero coefficients of `a` and `b` are ignored.
+/// - The modulus doesn't have to be prime, if the leading coefficient of `b` is invertible.
+/// ```
+/// use ads_rs::prelude::v1::math::poly::div_rem;
+///
+/// assert_eq!((vec![2], vec![]), div_rem(&[4, 6, 0], &[2, 3, 0, 10], 10));
+/// assert_eq!((vec![], vec![]), div_rem(&[], &[3], 7));
+/// ```
+/// # Panics
+/// Panics if `m == 0`, `b` is the zero polynomial or its leading coefficient isn't invertible.
+/// # Implementation details
+/// - The reversed quotient equals the reversed `a` divided by the reversed `b` as power series (from [`inverse_series`]).
+/// - Time complexity is O(n * log<sub>2</sub>(n))
+pub fn div_rem(a: &[u64], b: &[u64], m: u64) -> (Vec<u64>, Vec<u64>) {
+    assert!(m != 0, "modulus equals 0");
+
+    let a = trimmed(a, m);
+    let b = trimmed(b, m);
+    assert!(!b.is_empty(), "division by the zero polynomial");
+
+    if a.len() < b.len() {
+        return (vec![], a);
+    }
+
+    let k = a.len() - b.len() + 1;
+    let rev_a: Vec<u64> = a.iter().rev().take(k).copied().collect();
+    let rev_b: Vec<u64> = b.iter().rev().copied().collect();
+    let rev_b_inv = inverse_series(&rev_b, k, m).expect("leading coefficient isn't invertible");
+
+    let mut q = multiply(&rev_a, &rev_b_inv, m);
+    q.truncate(k);
+    q.reverse();
+
+    let bq = multiply(&b, &q, m);
+    let r: Vec<u64> = (0..b.len() - 1).map(|i| mod_sub(a[i], bq[i], m)).collect();
+
+    (trimmed(&q, m), trimmed(&r, m))
+}
+
+/// Reduces coefficients modulo `m` and removes leading zeros.
+pub(crate) fn trimmed(a: &[u64], m: u64) -> Vec<u64> {
+    let mut res: Vec<u64> = a.iter().map(|&c| c % m).collect();
+    while res.last() == Some(&0) {
+        res.pop();
+    }
+
+    res
+}
+
+/// Finds inverses of `1, 2, ..., n - 1` modulo a prime `p >= n`, the inverse of 0 is set to 0.
+fn inverses_up_to(n: usize, p: u64) -> Vec<u64> {
+    let mut inverses = vec![0; n.max(2)];
+    inverses[1] = 1 % p;
+
+    // p = (p / i) * i + p % i, so 1 / i = -(p / i) / (p % i)
+    for i in 2..n as u64 {
+        inverses[i as usize] = mod_sub(0, mod_mul(p / i, inverses[(p % i) as usize], p), p);
+    }
+
+    inverses
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v1::math::gcd;
+    use crate::v1::math::prime::SplitMix64;
+
+    fn random_poly(rng: &mut SplitMix64, len: usize, m: u64) -> Vec<u64> {
+        (0..len).map(|_| rng.next() % m).collect()
+    }
+
+    #[test]
+    fn inverse_series_works() {
+        let mut rng = SplitMix64(42);
+
+        for m in [998_244_353, 1_000_000_007, u64::MAX, 1 << 63] {
+            for n in [1, 2, 7, 64, 100, 1000] {
+                let mut a = random_poly(&mut rng, n + 3, m);
+                while gcd(a[0], m) != 1 {
+                    a[0] = rng.next() % m;
+                }
+
+                let inv = inverse_series(&a, n, m).unwrap();
+                let mut product = multiply(&a, &inv, m);
+                product.truncate(n);
+
+                let mut expected = vec![0; n];
+                expected[0] = 1;
+                assert_eq!(expected, product, "m = {m}, n = {n}");
+            }
+        }
+    }
+
+    #[test]
+    fn log_and_exp_are_inverse() {
+        let mut rng = SplitMix64(42);
+
+        for p in [998_244_353, 1_000_000_007, 18_446_744_073_709_551_557, 1009] {
+            for n in [1, 2, 5, 64, 300, 1000] {
+                let mut a = random_poly(&mut rng, n, p);
+                a[0] = 0;
+
+                let exp = exp_series(&a, n, p).unwrap();
+                assert_eq!(Some(a.clone()), log_series(&exp, n, p), "p = {p}, n = {n}");
+
+                a[0] = 1;
+                let log = log_series(&a, n, p).unwrap();
+                assert_eq!(Some(a), exp_series(&log, n, p), "p = {p}, n = {n}");
+            }
+        }
+    }
+
+    #[test]
+    fn div_rem_works() {
+        let mut rng = SplitMix64(42);
+
+        for m in [998_244_353, 1_000_000_007, u64::MAX, 1 << 63] {
+            for (n, k) in [
+                (1, 1),
+                (10, 3),
+                (100, 100),
+                (100, 101),
+                (1000, 300),
+                (1000, 999),
+            ] {
+                let a = random_poly(&mut rng, n, m);
+                let mut b = random_poly(&mut rng, k, m);
+                while gcd(b[k - 1], m) != 1 {
+                    b[k - 1] = rng.next() % m;
+                }
+
+                let (q, r) = div_rem(&a, &b, m);
+                let mut bq = multiply(&b, &q, m);
+                bq.resize(bq.len().max(r.len()).max(1), 0);
+                for (c, &x) in bq.iter_mut().zip(&r) {
+                    *c = mod_add(*c, x, m);
+                }
+
+                assert!(r.len() < b.len());
+                assert_eq!(trimmed(&a, m), trimmed(&bq, m), "m = {m}, n = {n}, k = {k}");
+            }
+        }
+    }
+
+    #[test]
+    fn inverses_up_to_works() {
+        for p in [2, 3, 7, 1009, 998_244_353] {
+            let inverses = inverses_up_to(1000.min(p as usize), p);
+
+            for (i, &inv) in inverses.iter().enumerate().skip(1) {
+                assert_eq!(1 % p, mod_mul(i as u64, inv, p));
+            }
+        }
+    }
+}
@@ -0,0 +1,263 @@
+use crate::v1::math::modular::{mod_mul_u128, mod_pow_u128};
+use crate::v1::math::{mod_mul, mod_pow};
+
+/// Primes used for trial division before the Miller–Rabin test.
+const SMALL_PRIMES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// Witnesses which make the Miller–Rabin test deterministic for every `u32`.
+const U32_WITNESSES: [u64; 3] = [2, 7, 61];
+
+/// Witnesses which make the Miller–Rabin test deterministic for every `u64`.
+const U64_WITNESSES: [u64; 7] = [2, 325, 9375, 28178, 450775, 9780504, 1795265022];
+
+/// Checks whether a number is prime.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::is_prime;
+///
+/// let res0 = is_prime(97);
+/// let res1 = is_prime(3215031751);
+/// let res2 = is_prime(18446744073709551557);
+///
+/// assert!(res0);
+/// assert!(!res1);
+/// assert!(res2);
+/// ```
+/// ## Corner cases
+/// 0 and 1 aren't prime.
+/// ```
+/// use ads_rs::prelude::v1::math::is_prime;
+///
+/// assert!(!is_prime(0));
+/// assert!(!is_prime(1));
+/// ```
+/// # Implementation details
+/// - Trial division by primes up to 37 is performed first.
+/// - Deterministic Miller–Rabin test is used with witnesses:
+///     - `2, 7, 61` for numbers below 2<sup>32</sup>.
+///     - `2, 325, 9375, 28178, 450775, 9780504, 1795265022` for the rest.
+/// - Modular multiplication is performed in `u128` (from [`mod_mul`]).
+/// - Time complexity is O(log<sub>2</sub>(n))
+pub fn is_prime(n: u64) -> bool {
+    for p in SMALL_PRIMES {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+
+    if n < 37 * 37 {
+        return n > 1;
+    }
+
+    let witnesses: &[u64] = if n <= u32::MAX as u64 {
+        &U32_WITNESSES
+    } else {
+        &U64_WITNESSES
+    };
+
+    let (d, s) = (
+        (n - 1) >> (n - 1).trailing_zeros(),
+        (n - 1).trailing_zeros(),
+    );
+
+    witnesses.iter().all(|&a| {
+        let a = a % n;
+        if a == 0 {
+            return true;
+        }
+
+        let mut x = mod_pow(a, d, n);
+        if x == 1 || x == n - 1 {
+            return true;
+        }
+
+        for _ in 1..s {
+            x = mod_mul(x, x, n);
+            if x == n - 1 {
+                return true;
+            }
+        }
+
+        false
+    })
+}
+
+/// Checks whether a `u128` number is prime with a probabilistic Miller–Rabin test.
+/// A composite number passes a single round with probability at most 1/4, so the probability
+/// of a wrong answer doesn't exceed 4<sup>`-rounds`</sup>. Primes are never rejected.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::is_prime_u128;
+///
+/// let res0 = is_prime_u128((1 << 127) - 1, 16);
+/// let res1 = is_prime_u128(18446744073709551557 * 18446744073709551533, 16);
+/// let res2 = is_prime_u128(97, 0);
+///
+/// assert!(res0);
+/// assert!(!res1);
+/// assert!(res2);
+/// ```
+/// ## Corner cases
+/// - Numbers below 2<sup>64</sup> are checked deterministically with [`is_prime`], regardless of `rounds`.
+/// - 0 and 1 aren't prime.
+/// # Implementation details
+/// - Witness 2 is always checked, then `rounds` pseudo-random witnesses seeded by `n`,
+///   so the result is reproducible.
+/// - Modular multiplication is performed without overflow by binary doubling.
+/// - Time complexity is O(rounds * log<sub>2</sub><sup>2</sup>(n))
+pub fn is_prime_u128(n: u128, rounds: u32) -> bool {
+    if let Ok(n) = u64::try_from(n) {
+        return is_prime(n);
+    }
+
+    for p in SMALL_PRIMES {
+        if n.is_multiple_of(p as u128) {
+            return false;
+        }
+    }
+
+    let (d, s) = (
+        (n - 1) >> (n - 1).trailing_zeros(),
+        (n - 1).trailing_zeros(),
+    );
+    let mut rng = SplitMix64((n as u64) ^ (n >> 64) as u64);
+
+    (0..=rounds).all(|round| {
+        let a = if round == 0 {
+            2
+        } else {
+            // a uniformly distributed witness in [2, n - 2]
+            let r = ((rng.next() as u128) << 64) | rng.next() as u128;
+            2 + r % (n - 3)
+        };
+
+        let mut x = mod_pow_u128(a, d, n);
+        if x == 1 || x == n - 1 {
+            return true;
+        }
+
+        for _ in 1..s {
+            x = mod_mul_u128(x, x, n);
+            if x == n - 1 {
+                return true;
+            }
+        }
+
+        false
+    })
+}
+
+/// SplitMix64 pseudo-random generator.
+pub(crate) struct SplitMix64(pub(crate) u64);
+
+impl SplitMix64 {
+    pub(crate) fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sieve(n: usize) -> Vec<bool> {
+        let mut is_prime = vec![true; n];
+        is_prime[0] = false;
+        is_prime[1] = false;
+
+        let mut i = 2;
+        while i * i < n {
+            if is_prime[i] {
+                for j in (i * i..n).step_by(i) {
+                    is_prime[j] = false;
+                }
+            }
+            i += 1;
+        }
+
+        is_prime
+    }
+
+    #[test]
+    fn is_prime_matches_sieve() {
+        let expected = sieve(3_000_000);
+
+        for (n, expected) in expected.iter().enumerate() {
+            assert_eq!(*expected, is_prime(n as u64), "n = {n}");
+        }
+    }
+
+    #[test]
+    fn is_prime_works() {
+        // arrange
+        let test_suits = [
+            // Strong pseudoprime to bases 2, 3, 5, 7
+            (3_215_031_751, false),
+            // Strong pseudoprime to bases 2, 7, 61
+            (4_759_123_141, false),
+            // Strong pseudoprime to the first 11 prime bases
+            (3_825_123_056_546_413_051, false),
+            // Carmichael number
+            (561, false),
+            // Largest 32-bit prime
+            (4_294_967_291, true),
+            // Largest 64-bit prime
+            (18_446_744_073_709_551_557, true),
+            // Product of two 32-bit primes
+            (4_294_967_291 * 4_294_967_279, false),
+            // Square of a prime
+            (4_294_967_291 * 4_294_967_291, false),
+            // Maximal value
+            (u64::MAX, false),
+        ];
+
+        // act
+        let result: Vec<bool> = test_suits.iter().map(|t| is_prime(t.0)).collect();
+
+        // assert
+        for i in 0..test_suits.len() {
+            assert_eq!(test_suits[i].1, result[i]);
+        }
+    }
+
+    #[test]
+    fn is_prime_u128_works() {
+        // arrange
+        let test_suits = [
+            // Mersenne primes
+            ((1 << 89) - 1, true),
+            ((1 << 127) - 1, true),
+            // Largest 128-bit prime
+            (u128::MAX - 158, true),
+            // Product of two 64-bit primes
+            (
+                18_446_744_073_709_551_557 * 18_446_744_073_709_551_533,
+                false,
+            ),
+            // Square of a 64-bit prime
+            (
+                18_446_744_073_709_551_557 * 18_446_744_073_709_551_557,
+                false,
+            ),
+            // Mersenne composite
+            ((1 << 67) - 1, false),
+            // Strong pseudoprime to base 2
+            (3_215_031_751, false),
+            // Maximal value
+            (u128::MAX, false),
+        ];
+
+        // act
+        let result: Vec<bool> = test_suits.iter().map(|t| is_prime_u128(t.0, 16)).collect();
+
+        // assert
+        for i in 0..test_suits.len() {
+            assert_eq!(test_suits[i].1, result[i]);
+        }
+    }
+}
@@ -0,0 +1,260 @@
+use crate::v1::math::{euler_phi, factorize, gcd, mod_pow};
+use std::iter::FusedIterator;
+
+/// Finds the multiplicative order of `a` modulo `m`: the smallest `k > 0` such that `a`<sup>`k`</sup>` ≡ 1 (mod m)`.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::multiplicative_order;
+///
+/// let res0 = multiplicative_order(2, 7);
+/// let res1 = multiplicative_order(3, 998_244_353);
+/// let res2 = multiplicative_order(10, 1 << 40);
+///
+/// assert_eq!(Some(3), res0);
+/// assert_eq!(Some(998_244_352), res1);
+/// assert_eq!(None, res2);
+/// ```
+/// ## Corner cases
+/// - The order exists only if `gcd(a, m) == 1`, otherwise `None` is returned.
+/// - Modulo 1 every number is congruent to 1, so the order equals 1.
+/// ```
+/// use ads_rs::prelude::v1::math::multiplicative_order;
+///
+/// assert_eq!(Some(1), multiplicative_order(0, 1));
+/// assert_eq!(None, multiplicative_order(0, 7));
+/// ```
+/// # Panics
+/// Panics if `m == 0`.
+/// # Implementation details
+/// - The order divides φ(m) (from [`euler_phi`]), so φ(m) is factorized (from [`factorize`])
+///   and divided by every prime while `a` raised to the quotient is still 1.
+/// - Time complexity is O(m<sup>1/4</sup> + log<sub>2</sub><sup>2</sup>(m)) expected.
+pub fn multiplicative_order(a: u64, m: u64) -> Option<u64> {
+    assert!(m != 0, "modulus equals 0");
+
+    if gcd(a, m) != 1 {
+        return None;
+    }
+
+    Some(order_factors(a, m).iter().map(|&(p, e)| p.pow(e)).product())
+}
+
+/// Finds the smallest primitive root modulo `m`: a number whose multiplicative order equals φ(m).
+/// Returns `None` if there are no primitive roots, which exist only for `m = 1, 2, 4, p`<sup>`k`</sup>`, 2p`<sup>`k`</sup>
+/// where `p` is an odd prime.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::primitive_root;
+///
+/// let res0 = primitive_root(998_244_353);
+/// let res1 = primitive_root(2 * 3u64.pow(20));
+/// let res2 = primitive_root(1_000_000_007);
+/// let res3 = primitive_root(15);
+///
+/// assert_eq!(Some(3), res0);
+/// assert_eq!(Some(5), res1);
+/// assert_eq!(Some(5), res2);
+/// assert_eq!(None, res3);
+/// ```
+/// ## Corner cases
+/// - Every number is congruent to 0 modulo 1, so 0 is the primitive root.
+/// - Powers of two above 4 have no primitive roots.
+/// ```
+/// use ads_rs::prelude::v1::math::primitive_root;
+///
+/// assert_eq!(Some(0), primitive_root(1));
+/// assert_eq!(Some(3), primitive_root(4));
+/// assert_eq!(None, primitive_root(8));
+/// ```
+/// # Panics
+/// Panics if `m == 0`.
+/// # Implementation details
+/// - Candidates are checked in increasing order (see [`primitive_roots`]).
+/// - Time complexity is O(m<sup>1/4</sup> + log<sub>2</sub><sup>3</sup>(m)) expected,
+///   because the smallest primitive root is small.
+pub fn primitive_root(m: u64) -> Option<u64> {
+    primitive_roots(m).next()
+}
+
+/// Finds all primitive roots modulo `m` in increasing order.
+/// Returns an empty iterator if there are no primitive roots (see [`primitive_root`]).
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::primitive_roots;
+///
+/// let res0: Vec<u64> = primitive_roots(7).collect();
+/// let res1: Vec<u64> = primitive_roots(18).collect();
+/// let res2: Vec<u64> = primitive_roots(12).collect();
+///
+/// assert_eq!(vec![3, 5], res0);
+/// assert_eq!(vec![5, 11], res1);
+/// assert!(res2.is_empty());
+/// ```
+/// ## Corner case
+/// There are φ(φ(m)) primitive roots if any.
+/// ```
+/// use ads_rs::prelude::v1::math::{euler_phi, primitive_roots};
+///
+/// assert_eq!(euler_phi(euler_phi(2 * 3u64.pow(7))), primitive_roots(2 * 3u64.pow(7)).count() as u64);
+/// ```
+/// # Panics
+/// Panics if `m == 0`.
+/// # Implementation details
+/// - `g` is a primitive root if it's coprime with `m` and `g`<sup>φ(m) / q</sup>` ≢ 1 (mod m)` for every prime `q` dividing φ(m),
+///   φ(m) is factorized once (from [`factorize`]).
+/// - Every step takes O(log<sub>2</sub><sup>2</sup>(m)) time.
+pub fn primitive_roots(m: u64) -> PrimitiveRoots {
+    assert!(m != 0, "modulus equals 0");
+
+    PrimitiveRoots::new(m)
+}
+
+/// Lazy iterator over primitive roots modulo a number, see [`primitive_roots`].
+#[derive(Debug, Clone)]
+pub struct PrimitiveRoots {
+    m: u64,
+    phi: u64,
+    phi_primes: Vec<u64>,
+    next: u64,
+}
+
+impl PrimitiveRoots {
+    fn new(m: u64) -> Self {
+        let phi = euler_phi(m);
+        let phi_primes = factorize(phi).into_iter().map(|(p, _)| p).collect();
+
+        Self {
+            m,
+            phi,
+            phi_primes,
+            next: if has_primitive_root(m) { 0 } else { m },
+        }
+    }
+}
+
+impl Iterator for PrimitiveRoots {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.next < self.m {
+            let g = self.next;
+            self.next += 1;
+
+            if gcd(g, self.m) == 1
+                && self
+                    .phi_primes
+                    .iter()
+                    .all(|&q| mod_pow(g, self.phi / q, self.m) != 1 % self.m)
+            {
+                return Some(g);
+            }
+        }
+
+        None
+    }
+}
+
+impl FusedIterator for PrimitiveRoots {}
+
+/// Checks whether `m` is 1, 2, 4, p^k or 2p^k for an odd prime `p`.
+fn has_primitive_root(m: u64) -> bool {
+    if m <= 4 {
+        return true;
+    }
+
+    let odd = if m.is_multiple_of(2) { m / 2 } else { m };
+    let factors = factorize(odd);
+
+    factors.len() == 1 && factors[0].0 != 2
+}
+
+/// Finds the factorization of the multiplicative order of `a` coprime with `m`.
+pub(crate) fn order_factors(a: u64, m: u64) -> Vec<(u64, u32)> {
+    let mut order = euler_phi(m);
+    let mut factors = factorize(order);
+
+    for (p, e) in factors.iter_mut() {
+        while *e > 0 && mod_pow(a, order / *p, m) == 1 % m {
+            order /= *p;
+            *e -= 1;
+        }
+    }
+
+    factors.retain(|&(_, e)| e > 0);
+    factors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn brute_force_order(a: u64, m: u64) -> Option<u64> {
+        let mut power = a % m;
+
+        for k in 1..=m {
+            if power == 1 % m {
+                return Some(k);
+            }
+            power = power * a % m;
+        }
+
+        None
+    }
+
+    #[test]
+    fn multiplicative_order_matches_brute_force() {
+        for m in 1..=400 {
+            for a in 0..m {
+                assert_eq!(
+                    brute_force_order(a, m),
+                    multiplicative_order(a, m),
+                    "a = {a}, m = {m}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn primitive_roots_match_brute_force() {
+        for m in 1..=400 {
+            let phi = euler_phi(m);
+            let expected: Vec<u64> = (0..m)
+                .filter(|&g| brute_force_order(g, m) == Some(phi))
+                .collect();
+
+            assert_eq!(
+                expected,
+                primitive_roots(m).collect::<Vec<u64>>(),
+                "m = {m}"
+            );
+            assert_eq!(expected.first().copied(), primitive_root(m), "m = {m}");
+        }
+    }
+
+    #[test]
+    fn primitive_root_works() {
+        // arrange
+        let test_suits = [
+            // NTT-friendly primes
+            (167_772_161, Some(3)),
+            (469_762_049, Some(3)),
+            (4_179_340_454_199_820_289, Some(3)),
+            // Largest 64-bit prime
+            (18_446_744_073_709_551_557, Some(2)),
+            // Doubled prime power
+            (2 * 4_294_967_291, Some(19)),
+            // Two odd primes
+            (4_294_967_291 * 4_294_967_279, None),
+            // Power of two
+            (1 << 63, None),
+        ];
+
+        // act
+        let result: Vec<Option<u64>> = test_suits.iter().map(|t| primitive_root(t.0)).collect();
+
+        // assert
+        for i in 0..test_suits.len() {
+            assert_eq!(test_suits[i].1, result[i]);
+        }
+    }
+}
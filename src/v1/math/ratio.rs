@@ -0,0 +1,594 @@
+use crate::v1::math::{gcd, Integer};
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::num::ParseIntError;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::str::FromStr;
+
+/// A rational number `numer / denom` kept in lowest terms.
+///
+/// The denominator is always positive and `gcd(numer, denom) == 1`, so equal numbers
+/// have equal representations and the derived `Eq` and `Hash` are consistent with the value.
+/// Operators panic on overflow, `checked_*` methods return `None` instead.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::Ratio;
+///
+/// let a = Ratio::new(6, -8);
+/// let b: Ratio<i64> = "5/12".parse().unwrap();
+///
+/// assert_eq!((-3, 4), (a.numer(), a.denom()));
+/// assert_eq!(Ratio::new(-1, 3), a + b);
+/// assert_eq!(Ratio::new(-5, 16), a * b);
+/// assert!(a < b);
+/// assert_eq!("-9/5", (a / b).to_string());
+/// assert_eq!((-2, -1, -2), ((a / b).floor(), (a / b).ceil(), (a / b).round()));
+/// ```
+/// ## Corner cases
+/// - Zero is represented as `0/1`.
+/// - `T::MIN` can't be negated, so e.g. `Ratio::new(i8::MIN, -1)` overflows.
+/// ```
+/// use ads_rs::prelude::v1::math::Ratio;
+///
+/// assert_eq!((0, 1), (Ratio::new(0, -5).numer(), Ratio::new(0, -5).denom()));
+/// assert_eq!(None, Ratio::checked_new(i8::MIN, -1));
+/// assert_eq!(None, Ratio::checked_new(1, 0));
+/// ```
+/// # Implementation details
+/// - Every value is normalized with Stein's algorithm (from [`gcd`]).
+/// - Addition divides both denominators by `g = gcd(b, d)` and reduces the numerator by its common factor
+///   with `g` before the denominator is multiplied out (Knuth's algorithm), multiplication
+///   cancels `gcd(a, d)` and `gcd(c, b)` first, so intermediate values stay as small as possible.
+/// - Comparison expands both numbers into continued fractions, so it never overflows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Ratio<T> {
+    numer: T,
+    denom: T,
+}
+
+impl<T: Integer> Ratio<T> {
+    /// Creates a ratio `numer / denom` reduced to lowest terms.
+    /// # Panics
+    /// Panics if `denom == 0` or the normalized ratio doesn't fit into `T`.
+    pub fn new(numer: T, denom: T) -> Self {
+        Self::checked_new(numer, denom).expect("invalid ratio")
+    }
+
+    /// Creates a ratio `numer / denom` reduced to lowest terms,
+    /// `None` if `denom == 0` or the normalized ratio doesn't fit into `T`.
+    pub fn checked_new(numer: T, denom: T) -> Option<Self> {
+        if denom == T::ZERO {
+            return None;
+        }
+
+        // gcd may wrap to a negative T::MIN, division by it is still exact
+        let g = gcd(numer, denom);
+        let (numer, denom) = (numer.checked_div(g)?, denom.checked_div(g)?);
+
+        if denom.is_negative() {
+            Some(Self {
+                numer: numer.checked_neg()?,
+                denom: denom.checked_neg()?,
+            })
+        } else {
+            Some(Self { numer, denom })
+        }
+    }
+
+    /// Creates a ratio equal to an integer.
+    pub fn from_integer(value: T) -> Self {
+        Self {
+            numer: value,
+            denom: T::ONE,
+        }
+    }
+
+    /// Returns the numerator in lowest terms.
+    pub fn numer(&self) -> T {
+        self.numer
+    }
+
+    /// Returns the positive denominator in lowest terms.
+    pub fn denom(&self) -> T {
+        self.denom
+    }
+
+    /// Checks whether the ratio is an integer.
+    pub fn is_integer(&self) -> bool {
+        self.denom == T::ONE
+    }
+
+    /// Returns `denom / numer`, `None` if the ratio equals zero or the result overflows.
+    pub fn checked_recip(self) -> Option<Self> {
+        Self::checked_new(self.denom, self.numer)
+    }
+
+    /// Returns `denom / numer`.
+    /// # Panics
+    /// Panics if the ratio equals zero or the result overflows.
+    pub fn recip(self) -> Self {
+        self.checked_recip().expect("invalid reciprocal")
+    }
+
+    /// Checked addition, `None` if the result doesn't fit into `T` or an intermediate value overflows.
+    /// For `a/b + c/d` the intermediate values are `a * (d / g)`, `c * (b / g)` and their sum, where `g = gcd(b, d)`.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.checked_add_scaled(rhs, false)
+    }
+
+    /// Checked subtraction, `None` if the result doesn't fit into `T` or an intermediate value overflows.
+    /// For `a/b - c/d` the intermediate values are `a * (d / g)`, `c * (b / g)` and their difference, where `g = gcd(b, d)`.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.checked_add_scaled(rhs, true)
+    }
+
+    /// Checked multiplication, `None` on overflow.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        // both pairs are coprime after cross reduction, so the result is in lowest terms
+        let g1 = gcd(self.numer, rhs.denom);
+        let g2 = gcd(rhs.numer, self.denom);
+        if g1 == T::ZERO || g2 == T::ZERO {
+            return Some(Self::from_integer(T::ZERO));
+        }
+
+        let numer = (self.numer.checked_div(g1)?).checked_mul(rhs.numer.checked_div(g2)?)?;
+        let denom = (self.denom.checked_div(g2)?).checked_mul(rhs.denom.checked_div(g1)?)?;
+
+        Self::checked_new(numer, denom)
+    }
+
+    /// Checked division, `None` if `rhs` equals zero or on overflow.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.checked_mul(rhs.checked_recip()?)
+    }
+
+    /// Finds `a/b + c/d` (or `a/b - c/d`) as `(a * d' + c * b') / (b' * d)` with `b' = b / g`, `d' = d / g`
+    /// and `g = gcd(b, d)`. The numerator can share only the factors of `g` with the denominator,
+    /// so it's reduced by `gcd(numer, g)` before the denominator is multiplied out.
+    fn checked_add_scaled(self, rhs: Self, negate: bool) -> Option<Self> {
+        let g = gcd(self.denom, rhs.denom);
+        let (lhs_denom, rhs_denom) = (self.denom / g, rhs.denom / g);
+        let lhs_numer = self.numer.checked_mul(rhs_denom)?;
+        let rhs_numer = rhs.numer.checked_mul(lhs_denom)?;
+
+        let numer = if negate {
+            lhs_numer.checked_sub(rhs_numer)?
+        } else {
+            lhs_numer.checked_add(rhs_numer)?
+        };
+
+        let g = gcd(numer, g);
+
+        Self::checked_new(numer / g, lhs_denom.checked_mul(rhs.denom / g)?)
+    }
+
+    /// Returns the largest integer less than or equal to the ratio.
+    pub fn floor(self) -> T {
+        self.div_rem_floor().0
+    }
+
+    /// Returns the smallest integer greater than or equal to the ratio.
+    /// # Panics
+    /// Panics if the result doesn't fit into `T`.
+    pub fn ceil(self) -> T {
+        let (q, r) = self.div_rem_floor();
+
+        if r == T::ZERO {
+            q
+        } else {
+            q + T::ONE
+        }
+    }
+
+    /// Returns the nearest integer, rounding half-way cases away from zero.
+    /// # Panics
+    /// Panics if the result doesn't fit into `T`.
+    pub fn round(self) -> T {
+        let (q, r) = self.div_rem_floor();
+
+        // compare the fractional part r / denom with 1/2 without overflow
+        match r.cmp(&(self.denom - r)) {
+            Ordering::Less => q,
+            Ordering::Greater => q + T::ONE,
+            Ordering::Equal if self.numer.is_negative() => q,
+            Ordering::Equal => q + T::ONE,
+        }
+    }
+
+    /// Returns `(floor(numer / denom), remainder)` with `0 <= remainder < denom`.
+    pub(crate) fn div_rem_floor(self) -> (T, T) {
+        let (q, r) = (self.numer / self.denom, self.numer % self.denom);
+
+        if r.is_negative() {
+            (q - T::ONE, r + self.denom)
+        } else {
+            (q, r)
+        }
+    }
+}
+
+impl<T: Integer> From<T> for Ratio<T> {
+    fn from(value: T) -> Self {
+        Self::from_integer(value)
+    }
+}
+
+impl<T: Integer> Ord for Ratio<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (mut lhs, mut rhs) = (*self, *other);
+        // the reciprocals of the fractional parts compare in the reverse order
+        let mut reversed = false;
+
+        loop {
+            let (lhs_q, lhs_r) = lhs.div_rem_floor();
+            let (rhs_q, rhs_r) = rhs.div_rem_floor();
+
+            let ord = match (lhs_q.cmp(&rhs_q), lhs_r == T::ZERO, rhs_r == T::ZERO) {
+                (Ordering::Equal, true, true) => Ordering::Equal,
+                (Ordering::Equal, true, false) => Ordering::Less,
+                (Ordering::Equal, false, true) => Ordering::Greater,
+                (Ordering::Equal, false, false) => {
+                    // compare lhs_r / lhs.denom with rhs_r / rhs.denom through their reciprocals
+                    (lhs, rhs) = (
+                        Self {
+                            numer: lhs.denom,
+                            denom: lhs_r,
+                        },
+                        Self {
+                            numer: rhs.denom,
+                            denom: rhs_r,
+                        },
+                    );
+                    reversed = !reversed;
+                    continue;
+                }
+                (ord, _, _) => ord,
+            };
+
+            return if reversed { ord.reverse() } else { ord };
+        }
+    }
+}
+
+impl<T: Integer> PartialOrd for Ratio<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Integer> Add for Ratio<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs).expect("ratio addition overflow")
+    }
+}
+
+impl<T: Integer> Sub for Ratio<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs).expect("ratio subtraction overflow")
+    }
+}
+
+impl<T: Integer> Mul for Ratio<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.checked_mul(rhs)
+            .expect("ratio multiplication overflow")
+    }
+}
+
+impl<T: Integer> Div for Ratio<T> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.checked_div(rhs)
+            .expect("ratio division by zero or overflow")
+    }
+}
+
+impl<T: Integer + Neg<Output = T>> Neg for Ratio<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            numer: -self.numer,
+            denom: self.denom,
+        }
+    }
+}
+
+impl<T: Integer> Display for Ratio<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.numer, self.denom)
+    }
+}
+
+/// An error which can be returned when parsing a [`Ratio`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseRatioError {
+    /// The numerator or the denominator isn't a valid integer.
+    InvalidInteger(ParseIntError),
+    /// The denominator equals zero.
+    ZeroDenominator,
+    /// The ratio doesn't fit into the integer type after normalization.
+    Overflow,
+}
+
+impl Display for ParseRatioError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidInteger(e) => write!(f, "invalid integer: {e}"),
+            Self::ZeroDenominator => write!(f, "zero denominator"),
+            Self::Overflow => write!(f, "ratio overflows"),
+        }
+    }
+}
+
+impl Error for ParseRatioError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::InvalidInteger(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Parses a ratio from `"p/q"` or `"p"`, the result is reduced to lowest terms.
+impl<T: Integer + FromStr<Err = ParseIntError>> FromStr for Ratio<T> {
+    type Err = ParseRatioError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (numer, denom) = match s.split_once('/') {
+            Some((numer, denom)) => (numer, denom),
+            None => (s, "1"),
+        };
+
+        let numer = numer.parse().map_err(ParseRatioError::InvalidInteger)?;
+        let denom: T = denom.parse().map_err(ParseRatioError::InvalidInteger)?;
+
+        if denom == T::ZERO {
+            return Err(ParseRatioError::ZeroDenominator);
+        }
+
+        Self::checked_new(numer, denom).ok_or(ParseRatioError::Overflow)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn all_i8_ratios(range: std::ops::RangeInclusive<i8>) -> Vec<Ratio<i8>> {
+        range
+            .clone()
+            .flat_map(|n| range.clone().filter_map(move |d| Ratio::checked_new(n, d)))
+            .collect()
+    }
+
+    #[test]
+    fn new_works() {
+        // arrange
+        let test_suits = [
+            // Regular case
+            ((6, 8), Some((3, 4))),
+            // Negative denominator
+            ((6, -8), Some((-3, 4))),
+            // Both negative
+            ((-6, -8), Some((3, 4))),
+            // Zero numerator
+            ((0, -8), Some((0, 1))),
+            // Zero denominator
+            ((1, 0), None),
+            // Minimal values
+            ((i8::MIN, i8::MIN), Some((1, 1))),
+            ((i8::MIN, 2), Some((-64, 1))),
+            ((2, i8::MIN), Some((-1, 64))),
+            ((1, i8::MIN), None),
+            ((i8::MIN, -1), None),
+        ];
+
+        // act
+        let result: Vec<Option<(i8, i8)>> = test_suits
+            .iter()
+            .map(|t| Ratio::checked_new(t.0 .0, t.0 .1).map(|r| (r.numer(), r.denom())))
+            .collect();
+
+        // assert
+        for i in 0..test_suits.len() {
+            assert_eq!(test_suits[i].1, result[i]);
+        }
+    }
+
+    #[test]
+    fn arithmetic_matches_wide() {
+        let ratios = all_i8_ratios(-12..=12);
+
+        for a in &ratios {
+            for b in &ratios {
+                let (an, ad, bn, bd) = (
+                    a.numer() as i64,
+                    a.denom() as i64,
+                    b.numer() as i64,
+                    b.denom() as i64,
+                );
+                let expected = |n: i64, d: i64| {
+                    Ratio::checked_new(n, d).and_then(|r| {
+                        Some((i8::try_from(r.numer()).ok()?, i8::try_from(r.denom()).ok()?))
+                    })
+                };
+                let actual = |r: Option<Ratio<i8>>| r.map(|r| (r.numer(), r.denom()));
+
+                // `None` is expected only if the result or an intermediate value doesn't fit
+                let g = gcd(ad, bd);
+                let (lhs, rhs) = (an * (bd / g), bn * (ad / g));
+                let fits = |x: i64| i8::try_from(x).is_ok();
+                let scaled = |res: i64| match fits(lhs) && fits(rhs) && fits(res) {
+                    true => expected(res, ad / g * bd),
+                    false => None,
+                };
+
+                assert_eq!(scaled(lhs + rhs), actual(a.checked_add(*b)));
+                assert_eq!(scaled(lhs - rhs), actual(a.checked_sub(*b)));
+                assert_eq!(expected(an * bn, ad * bd), actual(a.checked_mul(*b)));
+                assert_eq!(expected(an * bd, ad * bn), actual(a.checked_div(*b)));
+                assert_eq!((an * bd).cmp(&(bn * ad)), a.cmp(b));
+            }
+        }
+    }
+
+    #[test]
+    fn add_extreme_values_works() {
+        // arrange
+        let test_suits = [
+            // lcm(120, 105) overflows, but the sum is reduced by gcd(15, 15) first
+            ((1, 120), (1, 105), Some((1, 56))),
+            // the numerator is reduced by the common factor with gcd(b, d)
+            ((7, 120), (-1, 24), Some((1, 60))),
+            ((i8::MAX, 3), (i8::MIN, 3), Some((-1, 3))),
+            // the result doesn't fit
+            ((i8::MAX, 1), (1, 1), None),
+            // 127/4 fits, but 127 * (4 / 2) doesn't
+            ((i8::MAX, 2), (-i8::MAX, 4), None),
+        ];
+
+        // act
+        let result: Vec<Option<(i8, i8)>> = test_suits
+            .iter()
+            .map(|t| {
+                Ratio::new(t.0 .0, t.0 .1)
+                    .checked_add(Ratio::new(t.1 .0, t.1 .1))
+                    .map(|r| (r.numer(), r.denom()))
+            })
+            .collect();
+
+        // assert
+        for i in 0..test_suits.len() {
+            assert_eq!(test_suits[i].2, result[i]);
+        }
+    }
+
+    #[test]
+    fn cmp_extreme_values_works() {
+        // arrange
+        let test_suits = [
+            ((i8::MAX, 1), (i8::MAX, 2), Ordering::Greater),
+            ((i8::MIN, 1), (i8::MIN + 1, 1), Ordering::Less),
+            ((i8::MIN, 127), (-127, 126), Ordering::Greater),
+            ((126, 127), (125, 126), Ordering::Greater),
+            ((-126, 127), (-125, 126), Ordering::Less),
+            ((1, 127), (1, 126), Ordering::Less),
+        ];
+
+        // act
+        let result: Vec<Ordering> = test_suits
+            .iter()
+            .map(|t| Ratio::new(t.0 .0, t.0 .1).cmp(&Ratio::new(t.1 .0, t.1 .1)))
+            .collect();
+
+        // assert
+        for i in 0..test_suits.len() {
+            assert_eq!(test_suits[i].2, result[i]);
+        }
+    }
+
+    #[test]
+    fn rounding_works() {
+        // arrange
+        let test_suits = [
+            ((7, 2), (3, 4, 4)),
+            ((-7, 2), (-4, -3, -4)),
+            ((7, 3), (2, 3, 2)),
+            ((-7, 3), (-3, -2, -2)),
+            ((8, 3), (2, 3, 3)),
+            ((-8, 3), (-3, -2, -3)),
+            ((6, 3), (2, 2, 2)),
+            ((i8::MIN, 1), (i8::MIN, i8::MIN, i8::MIN)),
+            ((i8::MIN, 3), (-43, -42, -43)),
+            ((i8::MAX, 2), (63, 64, 64)),
+        ];
+
+        // act
+        let result: Vec<(i8, i8, i8)> = test_suits
+            .iter()
+            .map(|t| {
+                let r = Ratio::new(t.0 .0, t.0 .1);
+                (r.floor(), r.ceil(), r.round())
+            })
+            .collect();
+
+        // assert
+        for i in 0..test_suits.len() {
+            assert_eq!(test_suits[i].1, result[i]);
+        }
+    }
+
+    #[test]
+    fn parse_and_format_work() {
+        // arrange
+        let test_suits = [
+            ("3/4", Ok((3, 4))),
+            ("-6/8", Ok((-3, 4))),
+            ("6/-8", Ok((-3, 4))),
+            ("5", Ok((5, 1))),
+            ("1/0", Err(ParseRatioError::ZeroDenominator)),
+            ("-128/-1", Err(ParseRatioError::Overflow)),
+            (
+                "1/x",
+                Err(ParseRatioError::InvalidInteger(
+                    "x".parse::<i8>().unwrap_err(),
+                )),
+            ),
+            (
+                "",
+                Err(ParseRatioError::InvalidInteger(
+                    "".parse::<i8>().unwrap_err(),
+                )),
+            ),
+        ];
+
+        // act
+        let result: Vec<Result<Ratio<i8>, ParseRatioError>> =
+            test_suits.iter().map(|t| t.0.parse()).collect();
+
+        // assert
+        for i in 0..test_suits.len() {
+            assert_eq!(
+                test_suits[i].1,
+                result[i].clone().map(|r| (r.numer(), r.denom()))
+            );
+            if let Ok(r) = &result[i] {
+                assert_eq!(Ok(*r), r.to_string().parse());
+            }
+        }
+    }
+
+    #[test]
+    fn unsigned_works() {
+        let a = Ratio::new(u64::MAX, 3);
+        let b = Ratio::new(u64::MAX - 1, 2);
+        let c = Ratio::new(u64::MAX, u64::MAX - 1);
+
+        assert_eq!(Ratio::from_integer(u64::MAX / 3), a);
+        assert_eq!(
+            Some(Ratio::new(6148914691236517205, 9223372036854775807)),
+            a.checked_div(b)
+        );
+        assert_eq!(Some(Ratio::new(u64::MAX, 2)), b.checked_mul(c));
+        assert_eq!(None, a.checked_div(b).and_then(|r| r.checked_mul(c)));
+        assert_eq!(
+            Some(Ratio::from_integer(15372286728091293012)),
+            a.checked_add(b)
+        );
+        assert_eq!(None, a.checked_sub(b));
+        assert_eq!(
+            Some(Ratio::from_integer(3074457345618258602)),
+            b.checked_sub(a)
+        );
+        assert!(a < b);
+    }
+}
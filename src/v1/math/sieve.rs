@@ -0,0 +1,361 @@
+//! Prime sieves exposed as iterators.
+//!
+//! - [`LinearSieve`] keeps the smallest prime factor of every number up to N.
+//! - [`SegmentedSieve`] lazily yields primes of any `u64` range, keeping only one segment in memory.
+
+use crate::v1::math::{is_prime, isqrt};
+use std::iter::FusedIterator;
+
+/// Count of numbers sieved at once by [`SegmentedSieve`].
+const SEGMENT_SIZE: u64 = 1 << 16;
+
+/// Upper bound (exclusive) of base primes used by [`SegmentedSieve`].
+const BASE_LIMIT: u64 = 1 << 16;
+
+/// Linear sieve (sieve of Euler) which finds primes and smallest prime factors of numbers up to N.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::sieve::LinearSieve;
+///
+/// let sieve = LinearSieve::new(30);
+///
+/// assert_eq!(vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29], sieve.primes().collect::<Vec<_>>());
+/// assert_eq!(Some(3), sieve.smallest_prime_factor(27));
+/// assert!(sieve.is_prime(29));
+/// assert_eq!(vec![(2, 2), (7, 1)], sieve.factorize(28).collect::<Vec<_>>());
+/// ```
+/// ## Corner cases
+/// 0 and 1 have no smallest prime factor and empty factorizations.
+/// ```
+/// use ads_rs::prelude::v1::math::sieve::LinearSieve;
+///
+/// let sieve = LinearSieve::new(1);
+///
+/// assert_eq!(0, sieve.primes().count());
+/// assert_eq!(None, sieve.smallest_prime_factor(0));
+/// assert_eq!(None, sieve.smallest_prime_factor(1));
+/// assert_eq!(0, sieve.factorize(1).count());
+/// ```
+/// # Implementation details
+/// - Every composite number is crossed out exactly once, by its smallest prime factor.
+/// - Smallest prime factors are stored as `u32`, so N is limited by `u32::MAX`.
+/// - Time and memory complexity is O(N).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinearSieve {
+    spf: Vec<u32>,
+    primes: Vec<u32>,
+}
+
+impl LinearSieve {
+    /// Sieves numbers in `[0, n]`.
+    /// # Panics
+    /// Panics if `n > u32::MAX`.
+    pub fn new(n: usize) -> Self {
+        assert!(n <= u32::MAX as usize, "sieve limit exceeds u32::MAX");
+
+        let mut spf = vec![0u32; n + 1];
+        let mut primes = vec![];
+
+        for i in 2..=n {
+            if spf[i] == 0 {
+                spf[i] = i as u32;
+                primes.push(i as u32);
+            }
+
+            for &p in &primes {
+                if p > spf[i] || p as usize > n / i {
+                    break;
+                }
+
+                spf[i * p as usize] = p;
+            }
+        }
+
+        Self { spf, primes }
+    }
+
+    /// Returns the upper bound (inclusive) of the sieved numbers.
+    pub fn limit(&self) -> usize {
+        self.spf.len() - 1
+    }
+
+    /// Returns an iterator over primes up to the limit, in ascending order.
+    pub fn primes(&self) -> impl DoubleEndedIterator<Item = usize> + ExactSizeIterator + '_ {
+        self.primes.iter().map(|p| *p as usize)
+    }
+
+    /// Returns the smallest prime factor of `k`, `None` for 0 and 1.
+    /// # Panics
+    /// Panics if `k` exceeds the limit.
+    pub fn smallest_prime_factor(&self, k: usize) -> Option<usize> {
+        match self.spf[k] {
+            0 => None,
+            p => Some(p as usize),
+        }
+    }
+
+    /// Checks whether `k` is prime.
+    /// # Panics
+    /// Panics if `k` exceeds the limit.
+    pub fn is_prime(&self, k: usize) -> bool {
+        k > 1 && self.spf[k] as usize == k
+    }
+
+    /// Returns an iterator over pairs `(prime, exponent)` of the factorization of `k`, sorted by primes.
+    /// # Panics
+    /// Panics if `k` exceeds the limit.
+    pub fn factorize(&self, k: usize) -> impl Iterator<Item = (usize, u32)> + '_ {
+        assert!(k <= self.limit(), "number exceeds the sieve limit");
+
+        let mut k = k;
+        std::iter::from_fn(move || {
+            let p = self.smallest_prime_factor(k)?;
+
+            let mut exp = 0;
+            while k.is_multiple_of(p) {
+                k /= p;
+                exp += 1;
+            }
+
+            Some((p, exp))
+        })
+    }
+}
+
+/// Lazy segmented sieve of Eratosthenes which yields primes in `[lo, hi)` in ascending order.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::sieve::{primes_in, primes_up_to};
+///
+/// let res0: Vec<u64> = primes_up_to(30).collect();
+/// let res1: Vec<u64> = primes_in(1_000_000_000, 1_000_000_100).collect();
+/// let res2 = primes_in(u64::MAX - 100, u64::MAX).last();
+///
+/// assert_eq!(vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29], res0);
+/// assert_eq!(vec![1_000_000_007, 1_000_000_009, 1_000_000_021, 1_000_000_033, 1_000_000_087, 1_000_000_093, 1_000_000_097], res1);
+/// assert_eq!(Some(18446744073709551557), res2);
+/// ```
+/// ## Corner case
+/// The range is empty if `lo >= hi`.
+/// ```
+/// use ads_rs::prelude::v1::math::sieve::primes_in;
+///
+/// assert_eq!(None, primes_in(10, 10).next());
+/// assert_eq!(None, primes_in(10, 5).next());
+/// ```
+/// # Implementation details
+/// - The range is sieved by segments of 2<sup>16</sup> numbers, the next segment is sieved only when
+///   the previous one is exhausted.
+/// - Base primes up to `min(sqrt(segment end), 2`<sup>`16`</sup>`)` are stored and extended on demand.
+///   Numbers above 2<sup>32</sup> surviving the sieve are confirmed with Miller–Rabin test
+///   (from [`is_prime`]), so the memory complexity is O(2<sup>16</sup>) at most, not O(hi).
+/// - Time complexity is O((hi - lo) * log(log(hi))) for `hi <= 2`<sup>`32`</sup>,
+///   plus O(log(hi)) per survivor above it.
+#[derive(Debug, Clone)]
+pub struct SegmentedSieve {
+    // start of the next segment
+    next: u64,
+    hi: u64,
+    // all primes below `base_limit`
+    base: Vec<u64>,
+    base_limit: u64,
+    // current segment, composite[i] is about number segment_start + i
+    segment_start: u64,
+    composite: Vec<bool>,
+    pos: usize,
+}
+
+impl SegmentedSieve {
+    /// Creates a sieve over `[lo, hi)`.
+    pub fn new(lo: u64, hi: u64) -> Self {
+        Self {
+            next: lo,
+            hi,
+            base: vec![],
+            base_limit: 2,
+            segment_start: lo,
+            composite: vec![],
+            pos: 0,
+        }
+    }
+
+    /// Sieves the next segment, returns `false` if the range is exhausted.
+    fn sieve_next_segment(&mut self) -> bool {
+        if self.next >= self.hi {
+            return false;
+        }
+
+        let start = self.next;
+        let end = start.saturating_add(SEGMENT_SIZE).min(self.hi);
+        self.extend_base((isqrt(end - 1) + 1).min(BASE_LIMIT));
+
+        self.composite.clear();
+        self.composite.resize((end - start) as usize, false);
+        for i in start..end.min(2) {
+            self.composite[(i - start) as usize] = true;
+        }
+
+        for &p in &self.base {
+            let p_square = p * p;
+            if p_square >= end {
+                break;
+            }
+
+            // first multiple of p in the segment, but not less than p^2
+            let first = match start.div_ceil(p).checked_mul(p) {
+                Some(first) => first.max(p_square),
+                None => continue,
+            };
+
+            let mut m = first;
+            while m < end {
+                self.composite[(m - start) as usize] = true;
+                m = match m.checked_add(p) {
+                    Some(m) => m,
+                    None => break,
+                };
+            }
+        }
+
+        self.segment_start = start;
+        self.pos = 0;
+        self.next = end;
+
+        true
+    }
+
+    /// Makes `base` contain all primes below `limit`.
+    fn extend_base(&mut self, limit: u64) {
+        if limit <= self.base_limit {
+            return;
+        }
+
+        // grow geometrically to sieve base primes from scratch O(log) times only
+        let limit = limit.max(self.base_limit * 2).min(BASE_LIMIT);
+        let mut composite = vec![false; limit as usize];
+
+        self.base.clear();
+        for i in 2..limit as usize {
+            if composite[i] {
+                continue;
+            }
+
+            self.base.push(i as u64);
+            for j in (i * i..limit as usize).step_by(i) {
+                composite[j] = true;
+            }
+        }
+
+        self.base_limit = limit;
+    }
+}
+
+impl Iterator for SegmentedSieve {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            while self.pos < self.composite.len() {
+                let pos = self.pos;
+                self.pos += 1;
+
+                let n = self.segment_start + pos as u64;
+                // base primes don't cover numbers above BASE_LIMIT^2
+                if !self.composite[pos] && (n < BASE_LIMIT * BASE_LIMIT || is_prime(n)) {
+                    return Some(n);
+                }
+            }
+
+            if !self.sieve_next_segment() {
+                return None;
+            }
+        }
+    }
+}
+
+impl FusedIterator for SegmentedSieve {}
+
+/// Returns a lazy iterator over primes in `[lo, hi)`, see [`SegmentedSieve`].
+pub fn primes_in(lo: u64, hi: u64) -> SegmentedSieve {
+    SegmentedSieve::new(lo, hi)
+}
+
+/// Returns a lazy iterator over primes in `[0, n]`, see [`SegmentedSieve`].
+pub fn primes_up_to(n: u64) -> SegmentedSieve {
+    // u64::MAX isn't prime, so it's safe to exclude it
+    SegmentedSieve::new(0, n.saturating_add(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn linear_sieve_works() {
+        let sieve = LinearSieve::new(100_000);
+
+        assert_eq!(100_000, sieve.limit());
+        assert_eq!(9592, sieve.primes().count());
+        for k in 0..=100_000 {
+            assert_eq!(is_prime(k as u64), sieve.is_prime(k));
+
+            let product = sieve.factorize(k).map(|(p, e)| p.pow(e)).product::<usize>();
+            assert_eq!(k.max(1), product);
+        }
+    }
+
+    #[test]
+    fn segmented_sieve_matches_linear_sieve() {
+        let sieve = LinearSieve::new(300_000);
+
+        // arrange
+        let test_suits = [
+            (0, 300_001),
+            (0, 2),
+            (1, 3),
+            (2, 3),
+            (65_530, 65_550),
+            (1_000, 200_000),
+        ];
+
+        // act
+        let result: Vec<Vec<u64>> = test_suits
+            .iter()
+            .map(|t| primes_in(t.0, t.1).collect())
+            .collect();
+
+        // assert
+        for i in 0..test_suits.len() {
+            let (lo, hi) = test_suits[i];
+            let expected: Vec<u64> = sieve
+                .primes()
+                .map(|p| p as u64)
+                .filter(|p| lo <= *p && *p < hi)
+                .collect();
+            assert_eq!(expected, result[i]);
+        }
+    }
+
+    #[test]
+    fn segmented_sieve_wide_works() {
+        // arrange
+        let test_suits = [
+            (u64::MAX - 2_000, u64::MAX),
+            (1 << 40, (1 << 40) + 3_000),
+            (u32::MAX as u64 - 1_000, u32::MAX as u64 + 1_000),
+        ];
+
+        // act
+        let result: Vec<Vec<u64>> = test_suits
+            .iter()
+            .map(|t| primes_in(t.0, t.1).collect())
+            .collect();
+
+        // assert
+        for i in 0..test_suits.len() {
+            let (lo, hi) = test_suits[i];
+            let expected: Vec<u64> = (lo..hi).filter(|n| is_prime(*n)).collect();
+            assert_eq!(expected, result[i]);
+        }
+    }
+}
@@ -0,0 +1,448 @@
+use crate::v1::math::{crt, factorize, mod_add, mod_inv, mod_mul, mod_pow, mod_sub};
+use std::mem::swap;
+
+/// 2-adic valuation of `p - 1` from which Cipolla's algorithm is preferred over Tonelli–Shanks.
+const CIPOLLA_THRESHOLD: u32 = 16;
+
+/// Finds the Legendre symbol (a / p): 0 if `p` divides `a`, 1 if `a` is a quadratic residue modulo `p`
+/// and -1 otherwise.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::legendre;
+///
+/// let res0 = legendre(2, 7);
+/// let res1 = legendre(3, 7);
+/// let res2 = legendre(14, 7);
+///
+/// assert_eq!(1, res0);
+/// assert_eq!(-1, res1);
+/// assert_eq!(0, res2);
+/// ```
+/// ## Corner case
+/// Primality of `p` isn't checked, for composite `p` the result equals the Jacobi symbol.
+/// # Panics
+/// Panics if `p` is even.
+/// # Implementation details
+/// - The Legendre symbol is a special case of the Jacobi symbol (from [`jacobi`]),
+///   which is cheaper than Euler's criterion.
+/// - Time complexity is O(log<sub>2</sub>(p))
+pub fn legendre(a: u64, p: u64) -> i8 {
+    jacobi(a, p)
+}
+
+/// Finds the Jacobi symbol (a / n), the product of Legendre symbols (a / p) over prime divisors `p` of `n`.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::jacobi;
+///
+/// let res0 = jacobi(1001, 9907);
+/// let res1 = jacobi(19, 45);
+/// let res2 = jacobi(8, 21);
+/// let res3 = jacobi(21, 15);
+///
+/// assert_eq!(-1, res0);
+/// assert_eq!(1, res1);
+/// assert_eq!(-1, res2);
+/// assert_eq!(0, res3);
+/// ```
+/// ## Corner case
+/// (a / 1) equals 1 for any `a`.
+/// ```
+/// use ads_rs::prelude::v1::math::jacobi;
+///
+/// assert_eq!(1, jacobi(0, 1));
+/// ```
+/// # Panics
+/// Panics if `n` is even.
+/// # Implementation details
+/// - Binary algorithm is used: like in Stein's algorithm (see [`gcd`](crate::prelude::v1::math::gcd)),
+///   factors of 2 are removed and the smaller number is subtracted from the larger one,
+///   the sign is flipped by the second supplement and the quadratic reciprocity law.
+/// - Time complexity is O(log<sub>2</sub><sup>2</sup>(n))
+pub fn jacobi(a: u64, n: u64) -> i8 {
+    assert!(n % 2 == 1, "n is even");
+
+    let (mut a, mut n) = (a % n, n);
+    let mut res = 1;
+
+    while a != 0 {
+        // (2 / n) = -1 iff n = 3 or 5 (mod 8)
+        let zeros = a.trailing_zeros();
+        a >>= zeros;
+        if zeros % 2 == 1 && (n % 8 == 3 || n % 8 == 5) {
+            res = -res;
+        }
+
+        // (a / n) = -(n / a) iff both are 3 (mod 4)
+        if a < n {
+            swap(&mut a, &mut n);
+            if a % 4 == 3 && n % 4 == 3 {
+                res = -res;
+            }
+        }
+
+        a -= n;
+    }
+
+    if n == 1 {
+        res
+    } else {
+        0
+    }
+}
+
+/// Finds a square root modulo a prime: `x` such that `x`<sup>2</sup>` ≡ a (mod p)`.
+/// Returns the smaller of the two roots `x` and `p - x`, or `None` if `a` isn't a quadratic residue.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::sqrt_mod_prime;
+///
+/// let res0 = sqrt_mod_prime(10, 13);
+/// let res1 = sqrt_mod_prime(5, 13);
+/// let res2 = sqrt_mod_prime(2, 1_000_000_007);
+/// let res3 = sqrt_mod_prime(5, 998_244_353);
+///
+/// assert_eq!(Some(6), res0);
+/// assert_eq!(None, res1);
+/// assert_eq!(Some(59_713_600), res2);
+/// assert_eq!(None, res3);
+/// ```
+/// ## Corner cases
+/// - The square root of 0 is 0.
+/// - Every number is a square modulo 2.
+/// ```
+/// use ads_rs::prelude::v1::math::sqrt_mod_prime;
+///
+/// assert_eq!(Some(0), sqrt_mod_prime(26, 13));
+/// assert_eq!(Some(1), sqrt_mod_prime(3, 2));
+/// ```
+/// # Panics
+/// Panics if `p` is even and isn't 2. Primality of `p` isn't checked.
+/// # Implementation details
+/// - Residuosity is checked with the Legendre symbol (from [`legendre`]).
+/// - For `p ≡ 3 (mod 4)` the root equals `a`<sup>`(p + 1) / 4`</sup>.
+/// - Otherwise Tonelli–Shanks algorithm is used, unless 2<sup>16</sup> divides `p - 1`:
+///   its time grows quadratically with the power of 2, so Cipolla's algorithm is used instead.
+/// - Time complexity is O(log<sub>2</sub><sup>2</sup>(p)) expected.
+pub fn sqrt_mod_prime(a: u64, p: u64) -> Option<u64> {
+    if p == 2 {
+        return Some(a % 2);
+    }
+
+    let a = a % p;
+    if a == 0 {
+        return Some(0);
+    }
+
+    if legendre(a, p) != 1 {
+        return None;
+    }
+
+    let root = if p % 4 == 3 {
+        mod_pow(a, (p + 1) / 4, p)
+    } else if (p - 1).trailing_zeros() < CIPOLLA_THRESHOLD {
+        tonelli_shanks(a, p)
+    } else {
+        cipolla(a, p)
+    };
+
+    Some(root.min(p - root))
+}
+
+/// Finds a square root modulo a prime power: `x` such that `x`<sup>2</sup>` ≡ a (mod p`<sup>`k`</sup>`)`.
+/// Returns one of the roots or `None` if there are none.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::sqrt_mod_prime_power;
+///
+/// let res0 = sqrt_mod_prime_power(10, 13, 3);
+/// let res1 = sqrt_mod_prime_power(17, 2, 10);
+/// let res2 = sqrt_mod_prime_power(7 * 7 * 3, 7, 4);
+///
+/// assert_eq!(Some(1046), res0);
+/// assert_eq!(Some(233), res1);
+/// assert_eq!(None, res2);
+/// ```
+/// ## Corner cases
+/// - `a` divisible by p<sup>2t</sup> has a root divisible by p<sup>t</sup>.
+/// - An odd number is a square modulo 2<sup>k</sup> for `k >= 3` only if it equals 1 modulo 8.
+/// ```
+/// use ads_rs::prelude::v1::math::sqrt_mod_prime_power;
+///
+/// assert_eq!(Some(80), sqrt_mod_prime_power(25 * 6, 5, 4));
+/// assert_eq!(None, sqrt_mod_prime_power(5, 2, 3));
+/// assert_eq!(Some(1), sqrt_mod_prime_power(5, 2, 2));
+/// ```
+/// # Panics
+/// Panics if `p` is even and isn't 2, or p<sup>k</sup> doesn't fit into `u64`. Primality of `p` isn't checked.
+/// # Implementation details
+/// - The root modulo `p` (from [`sqrt_mod_prime`]) is lifted with Hensel's lemma:
+///   `x := x - (x`<sup>2</sup>` - a) / (2x)`, which doubles the precision every step.
+/// - For `p = 2` the root modulo 8 is lifted one bit at a time.
+/// - Time complexity is O(log<sub>2</sub><sup>2</sup>(p<sup>k</sup>)) expected.
+pub fn sqrt_mod_prime_power(a: u64, p: u64, k: u32) -> Option<u64> {
+    assert!(p == 2 || p % 2 == 1, "p is even");
+
+    let pk = p.checked_pow(k).expect("p^k overflows u64");
+    let a = a % pk;
+
+    if a == 0 {
+        return Some(0);
+    }
+
+    // a = p^v * b, the root is p^(v / 2) * sqrt(b) for even v
+    let mut v = 0;
+    let mut b = a;
+    while b.is_multiple_of(p) {
+        b /= p;
+        v += 1;
+    }
+
+    if v % 2 == 1 {
+        return None;
+    }
+
+    let rest = pk / p.pow(v);
+    let root = if p == 2 {
+        sqrt_mod_power_of_two(b % rest, k - v)?
+    } else {
+        hensel_lift(sqrt_mod_prime(b, p)?, b, p, k - v)
+    };
+
+    Some(root * p.pow(v / 2))
+}
+
+/// Finds a square root modulo any number: `x` such that `x`<sup>2</sup>` ≡ a (mod m)`.
+/// Returns one of the roots or `None` if there are none.
+/// # Examples
+/// ```
+/// use ads_rs::prelude::v1::math::sqrt_mod;
+///
+/// let res0 = sqrt_mod(4, 15);
+/// let res1 = sqrt_mod(1_000_000_000_000, 2_147_483_647 * 3 * 49);
+/// let res2 = sqrt_mod(5, 15);
+///
+/// assert_eq!(Some(7), res0);
+/// assert_eq!(Some(1_000_000), res1);
+/// assert_eq!(None, res2);
+/// ```
+/// ## Corner case
+/// Modulo 1 every number is congruent to 0, so the root equals 0.
+/// ```
+/// use ads_rs::prelude::v1::math::sqrt_mod;
+///
+/// assert_eq!(Some(0), sqrt_mod(5, 1));
+/// ```
+/// # Panics
+/// Panics if `m == 0`.
+/// # Implementation details
+/// - Roots modulo every prime power dividing `m` are found with [`sqrt_mod_prime_power`]
+///   using the factorization (from [`factorize`]), then they're combined with [`crt`].
+/// - Time complexity is O(m<sup>1/4</sup> + log<sub>2</sub><sup>3</sup>(m)) expected.
+pub fn sqrt_mod(a: u64, m: u64) -> Option<u64> {
+    assert!(m != 0, "modulus equals 0");
+
+    let congruences = factorize(m)
+        .into_iter()
+        .map(|(p, k)| Some((sqrt_mod_prime_power(a, p, k)?, p.pow(k))))
+        .collect::<Option<Vec<_>>>()?;
+
+    crt(&congruences).map(|(x, _)| x)
+}
+
+/// Tonelli–Shanks algorithm for a quadratic residue `a` modulo an odd prime `p`.
+fn tonelli_shanks(a: u64, p: u64) -> u64 {
+    // p - 1 = q * 2^s
+    let s = (p - 1).trailing_zeros();
+    let q = (p - 1) >> s;
+    let z = (2..).find(|&z| legendre(z, p) == -1).unwrap();
+
+    let (mut m, mut c) = (s, mod_pow(z, q, p));
+    let (mut t, mut root) = (mod_pow(a, q, p), mod_pow(a, q.div_ceil(2), p));
+
+    while t != 1 {
+        // the least i such that t^(2^i) = 1
+        let mut i = 0;
+        let mut t2i = t;
+        while t2i != 1 {
+            t2i = mod_mul(t2i, t2i, p);
+            i += 1;
+        }
+
+        let b = mod_pow(c, 1 << (m - i - 1), p);
+        m = i;
+        c = mod_mul(b, b, p);
+        t = mod_mul(t, c, p);
+        root = mod_mul(root, b, p);
+    }
+
+    root
+}
+
+/// Cipolla's algorithm for a quadratic residue `a` modulo an odd prime `p`.
+fn cipolla(a: u64, p: u64) -> u64 {
+    // w^2 - a is a non-residue, so F_p(√(w^2 - a)) is a field where (w + √(w^2 - a))^((p + 1) / 2) = √a
+    let w = (1..)
+        .find(|&w| legendre(mod_sub(mod_mul(w, w, p), a, p), p) == -1)
+        .unwrap();
+    let d = mod_sub(mod_mul(w, w, p), a, p);
+
+    let mul = |(x0, x1): (u64, u64), (y0, y1): (u64, u64)| {
+        (
+            mod_add(mod_mul(x0, y0, p), mod_mul(mod_mul(x1, y1, p), d, p), p),
+            mod_add(mod_mul(x0, y1, p), mod_mul(x1, y0, p), p),
+        )
+    };
+
+    let (mut res, mut base, mut exp) = ((1, 0), (w, 1), p.div_ceil(2));
+    while exp > 0 {
+        if exp & 1 == 1 {
+            res = mul(res, base);
+        }
+        base = mul(base, base);
+        exp >>= 1;
+    }
+
+    res.0
+}
+
+/// Lifts a root of `b` modulo an odd prime `p` to a root modulo `p^k` with Hensel's lemma.
+fn hensel_lift(mut root: u64, b: u64, p: u64, k: u32) -> u64 {
+    let mut precision = 1;
+
+    while precision < k {
+        precision = (precision * 2).min(k);
+        let m = p.pow(precision);
+
+        // x := x - (x^2 - b) / (2x), 2x is invertible, because p is odd and p doesn't divide x
+        let error = mod_sub(mod_mul(root, root, m), b % m, m);
+        let inv = mod_inv(mod_mul(2, root, m), m).unwrap();
+        root = mod_sub(root, mod_mul(error, inv, m), m);
+    }
+
+    root
+}
+
+/// Finds a square root of an odd `b` modulo `2^k`.
+fn sqrt_mod_power_of_two(b: u64, k: u32) -> Option<u64> {
+    match k {
+        0 | 1 => return Some(b % 2),
+        2 => return (b % 4 == 1).then_some(1),
+        _ if b % 8 != 1 => return None,
+        _ => {}
+    }
+
+    // if x^2 = b (mod 2^j), then x or x + 2^(j - 1) is a root modulo 2^(j + 1)
+    let mut root: u64 = 1;
+    for j in 3..k {
+        let m = 1 << (j + 1);
+        if mod_mul(root, root, m) != b % m {
+            root += 1 << (j - 1);
+        }
+    }
+
+    Some(root)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v1::math::{gcd, is_prime};
+
+    fn euler_criterion(a: u64, p: u64) -> i8 {
+        match mod_pow(a, (p - 1) / 2, p) {
+            0 => 0,
+            1 => 1,
+            _ => -1,
+        }
+    }
+
+    #[test]
+    fn jacobi_matches_legendre_product() {
+        for n in (1..400).step_by(2) {
+            for a in 0..2 * n {
+                let expected = factorize(n)
+                    .iter()
+                    .map(|&(p, e)| euler_criterion(a, p).pow(e))
+                    .product::<i8>();
+
+                assert_eq!(expected, jacobi(a, n), "a = {a}, n = {n}");
+            }
+        }
+    }
+
+    #[test]
+    fn sqrt_mod_prime_matches_brute_force() {
+        // 7681 - 1 = 2^9 * 15, 65537 - 1 = 2^16, 786433 - 1 = 2^18 * 3
+        let primes = (2..1000)
+            .filter(|&p| is_prime(p))
+            .chain([7681, 65537, 786433]);
+
+        for p in primes {
+            let mut squares = vec![false; p as usize];
+            for x in 0..p {
+                squares[(x * x % p) as usize] = true;
+            }
+
+            for a in 0..p.min(3000) {
+                let root = sqrt_mod_prime(a, p);
+
+                assert_eq!(squares[a as usize], root.is_some(), "a = {a}, p = {p}");
+                if let Some(root) = root {
+                    assert!(root <= p - root || root == 0);
+                    assert_eq!(a, root * root % p);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sqrt_mod_matches_brute_force() {
+        for m in 1..=600 {
+            let mut squares = vec![false; m as usize];
+            for x in 0..m {
+                squares[(x * x % m) as usize] = true;
+            }
+
+            for a in 0..m {
+                let root = sqrt_mod(a, m);
+
+                assert_eq!(squares[a as usize], root.is_some(), "a = {a}, m = {m}");
+                if let Some(root) = root {
+                    assert_eq!(a, root * root % m);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sqrt_mod_works() {
+        // arrange
+        let test_suits = [
+            // Large prime powers
+            (2, 1_000_000_007u64.pow(2)),
+            (63, 3u64.pow(39) * 2),
+            (17, 1 << 63),
+            (9 << 60, 1 << 63),
+            // Product of large primes
+            (4_294_967_291 * 2, 4_294_967_291 * 4_294_967_279),
+            // Large prime with a high power of 2 in p - 1
+            (5, 4_179_340_454_199_820_289),
+            // Maximal values
+            (u64::MAX - 9, u64::MAX),
+            (u64::MAX, u64::MAX),
+        ];
+
+        // act
+        let result: Vec<Option<u64>> = test_suits.iter().map(|t| sqrt_mod(t.0, t.1)).collect();
+
+        // assert
+        for i in 0..test_suits.len() {
+            let (a, m) = test_suits[i];
+            let root = result[i].unwrap_or_else(|| panic!("a = {a}, m = {m}"));
+
+            assert_eq!(a % m, mod_mul(root, root, m));
+            assert!(gcd(root, m) == 1 || gcd(a, m) != 1);
+        }
+    }
+}